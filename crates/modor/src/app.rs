@@ -1,10 +1,12 @@
-use crate::{platform, FromApp, State};
+use crate::{logging, platform, ElapsedTime, Events, FrameCount, FromApp, State};
 use derivative::Derivative;
 use fxhash::FxHashMap;
+use instant::Instant;
 use log::{debug, Level};
 use std::any;
 use std::any::{Any, TypeId};
 use std::marker::PhantomData;
+use std::time::Duration;
 
 /// The entrypoint of the engine.
 ///
@@ -15,6 +17,8 @@ use std::marker::PhantomData;
 pub struct App {
     state_indexes: FxHashMap<TypeId, usize>,
     states: Vec<StateData>, // ensures deterministic update order
+    is_profiling_enabled: bool,
+    last_update_profile: Vec<(&'static str, Duration)>,
 }
 
 impl App {
@@ -36,33 +40,114 @@ impl App {
         let mut app = Self {
             state_indexes: FxHashMap::default(),
             states: vec![],
+            is_profiling_enabled: false,
+            last_update_profile: vec![],
         };
+        app.create::<ElapsedTime>();
+        app.create::<FrameCount>();
         app.get_mut::<T>();
         debug!("App initialized");
         app
     }
 
+    /// Sets the minimum `level` of the logs to display for a specific `target`.
+    ///
+    /// The level also applies to all sub-targets (e.g. `modor_graphics::texture` is also impacted
+    /// when the level of `modor_graphics` is set). If multiple configured targets match a log, the
+    /// most specific one is used.
+    ///
+    /// Logs of targets without configured level are filtered using the level passed to
+    /// [`App::new`].
+    ///
+    /// The configured levels are shared by all apps of the process.
+    pub fn set_log_level(target: impl Into<String>, level: Level) {
+        logging::set_target_level(target.into(), level);
+    }
+
     /// Update all states registered in the app.
     ///
     /// [`State::update`] method is called for each registered state.
     ///
     /// States are updated in the order in which they are created.
     ///
+    /// At the end of the update, [`FrameCount`] and [`ElapsedTime`] are refreshed.
+    ///
     /// # Panics
     ///
     /// This will panic if any state is already borrowed.
     pub fn update(&mut self) {
         debug!("Run update app...");
+        self.last_update_profile.clear();
         for state_index in 0..self.states.len() {
             let state = &mut self.states[state_index];
             let mut value = state.value.take().expect("state is already borrowed");
             let update_fn = state.update_fn;
-            update_fn(&mut *value, self);
+            if self.is_profiling_enabled {
+                let start = Instant::now();
+                update_fn(&mut *value, self);
+                let name = self.states[state_index].name;
+                self.last_update_profile.push((name, start.elapsed()));
+            } else {
+                update_fn(&mut *value, self);
+            }
             self.states[state_index].value = Some(value);
         }
+        self.get_mut::<FrameCount>().count += 1;
+        self.get_mut::<ElapsedTime>().refresh();
         debug!("App updated");
     }
 
+    /// Enables or disables profiling of state updates.
+    ///
+    /// When enabled, the duration of [`State::update`] is measured for each state during
+    /// [`App::update`], and can be retrieved with [`App::last_update_profile`].
+    ///
+    /// Profiling is disabled by default.
+    pub fn set_profiling(&mut self, is_enabled: bool) {
+        self.is_profiling_enabled = is_enabled;
+    }
+
+    /// Returns the duration of each state update during the last [`App::update`].
+    ///
+    /// Each item contains the type name of the state and its update duration. Items are ordered
+    /// by update order.
+    ///
+    /// The returned slice is empty if profiling was disabled during the last update
+    /// (see [`App::set_profiling`]).
+    pub fn last_update_profile(&self) -> &[(&'static str, Duration)] {
+        &self.last_update_profile
+    }
+
+    /// Runs `count` updates of the app.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if any state is already borrowed.
+    pub fn run_for(&mut self, count: u32) {
+        for _ in 0..count {
+            self.update();
+        }
+    }
+
+    /// Runs updates of the app until `f` returns `true`.
+    ///
+    /// `f` is called before each update. At most `max_updates` updates are run.
+    ///
+    /// The method returns whether `f` has returned `true`.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if any state is already borrowed.
+    pub fn run_until(&mut self, max_updates: u32, mut f: impl FnMut(&mut Self) -> bool) -> bool {
+        for _ in 0..max_updates {
+            if f(self) {
+                return true;
+            }
+            self.update();
+        }
+        f(self)
+    }
+
     /// Returns a handle to a state.
     ///
     /// The state is created using [`FromApp::from_app`](crate::FromApp::from_app)
@@ -102,6 +187,36 @@ impl App {
         self.state_mut(state_index)
     }
 
+    /// Returns an immutable reference to a state if it exists.
+    ///
+    /// Unlike [`App::get_mut`], the state is not created if it doesn't exist.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if state `T` is already borrowed.
+    pub fn try_get<T>(&self) -> Option<&T>
+    where
+        T: State,
+    {
+        let state_index = *self.state_indexes.get(&TypeId::of::<T>())?;
+        Some(self.state(state_index))
+    }
+
+    /// Returns a mutable reference to a state if it exists.
+    ///
+    /// Unlike [`App::get_mut`], the state is not created if it doesn't exist.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if state `T` is already borrowed.
+    pub fn try_get_mut<T>(&mut self) -> Option<&mut T>
+    where
+        T: State,
+    {
+        let state_index = *self.state_indexes.get(&TypeId::of::<T>())?;
+        Some(self.state_mut(state_index))
+    }
+
     /// Borrows a state without borrowing the app.
     ///
     /// The method returns the output of `f`.
@@ -122,6 +237,20 @@ impl App {
         self.take_state(state_index, f)
     }
 
+    /// Sends an event of type `E`.
+    ///
+    /// The event can then be read using an [`EventReader`](crate::EventReader).
+    ///
+    /// # Panics
+    ///
+    /// This will panic if state [`Events<E>`](Events) is already borrowed.
+    pub fn send_event<E>(&mut self, event: E)
+    where
+        E: 'static,
+    {
+        self.get_mut::<Events<E>>().send(event);
+    }
+
     #[allow(clippy::map_entry)]
     fn state_index_or_create<T>(&mut self) -> usize
     where
@@ -141,6 +270,18 @@ impl App {
         }
     }
 
+    fn state<T>(&self, state_index: usize) -> &T
+    where
+        T: State,
+    {
+        self.states[state_index]
+            .value
+            .as_ref()
+            .unwrap_or_else(|| panic!("state `{}` already borrowed", any::type_name::<T>()))
+            .downcast_ref::<T>()
+            .expect("internal error: misconfigured state")
+    }
+
     fn state_mut<T>(&mut self, state_index: usize) -> &mut T
     where
         T: State,
@@ -207,12 +348,7 @@ where
     ///
     /// This will panic if the state is already borrowed.
     pub fn get(self, app: &App) -> &T {
-        app.states[self.index]
-            .value
-            .as_ref()
-            .unwrap_or_else(|| panic!("state `{}` already borrowed", any::type_name::<T>()))
-            .downcast_ref::<T>()
-            .expect("internal error: misconfigured state")
+        app.state(self.index)
     }
 
     /// Returns a mutable reference to the state.
@@ -241,6 +377,7 @@ where
 #[derive(Debug)]
 struct StateData {
     value: Option<Box<dyn Any>>,
+    name: &'static str,
     update_fn: fn(&mut dyn Any, &mut App),
 }
 
@@ -251,6 +388,7 @@ impl StateData {
     {
         Self {
             value: Some(Box::new(value)),
+            name: any::type_name::<T>(),
             update_fn: |value, app| {
                 let value = value
                     .downcast_mut::<T>()
@@ -0,0 +1,122 @@
+use crate::{App, State};
+use derivative::Derivative;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// A container that stores all events of type `E` sent recently.
+///
+/// Events are read using an [`EventReader`].
+///
+/// An event is removed once it has been sent for [`Events::retention`] updates of the container.
+///
+/// # Examples
+///
+/// ```
+/// # use modor::*;
+/// #
+/// struct PlayerDied;
+///
+/// #[derive(FromApp)]
+/// struct DeathCounter {
+///     reader: EventReader<PlayerDied>,
+///     count: usize,
+/// }
+///
+/// impl State for DeathCounter {
+///     fn update(&mut self, app: &mut App) {
+///         self.count += self.reader.read(app).len();
+///     }
+/// }
+///
+/// fn kill_player(app: &mut App) {
+///     app.send_event(PlayerDied);
+/// }
+/// ```
+#[derive(Debug, Derivative)]
+#[derivative(Default(bound = ""))]
+pub struct Events<E> {
+    /// Number of updates during which an event is kept.
+    ///
+    /// Default is `2`.
+    #[derivative(Default(value = "2"))]
+    pub retention: u32,
+    queue: VecDeque<EventData<E>>,
+    next_id: u64,
+    update_count: u64,
+}
+
+impl<E> State for Events<E>
+where
+    E: 'static,
+{
+    fn update(&mut self, _app: &mut App) {
+        self.update_count += 1;
+        while self
+            .queue
+            .front()
+            .is_some_and(|event| event.update_id + u64::from(self.retention) <= self.update_count)
+        {
+            self.queue.pop_front();
+        }
+    }
+}
+
+impl<E> Events<E> {
+    /// Sends an event.
+    pub fn send(&mut self, event: E) {
+        self.queue.push_back(EventData {
+            id: self.next_id,
+            update_id: self.update_count,
+            value: event,
+        });
+        self.next_id += 1;
+    }
+
+    /// Returns an iterator on all stored events.
+    pub fn iter(&self) -> impl Iterator<Item = &E> {
+        self.queue.iter().map(|event| &event.value)
+    }
+}
+
+/// A reader of the events of type `E`.
+///
+/// Each reader has its own cursor, so that multiple readers can read the same events.
+///
+/// # Examples
+///
+/// See [`Events`].
+#[derive(Debug, Derivative)]
+#[derivative(Default(bound = ""))]
+pub struct EventReader<E> {
+    next_id: u64,
+    phantom: PhantomData<fn(E)>,
+}
+
+impl<E> EventReader<E>
+where
+    E: 'static,
+{
+    /// Returns the events sent since the last read.
+    ///
+    /// The first read returns all stored events.
+    ///
+    /// Events already removed from [`Events`] are missed if the reader has not read them in time.
+    pub fn read<'a>(&mut self, app: &'a mut App) -> Vec<&'a E> {
+        let events = app.get_mut::<Events<E>>();
+        let read_events = events
+            .queue
+            .iter()
+            .filter(|event| event.id >= self.next_id)
+            .map(|event| &event.value)
+            .collect();
+        self.next_id = events.next_id;
+        read_events
+    }
+}
+
+#[derive(Debug)]
+struct EventData<E> {
+    id: u64,
+    update_id: u64,
+    value: E,
+}
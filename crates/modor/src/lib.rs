@@ -53,31 +53,49 @@ pub use log;
 pub use wasm_bindgen_test;
 
 mod app;
+mod events;
 mod from_app;
 mod globals;
+mod logging;
 mod platform;
 mod state;
+mod state_stack;
+mod throttle;
+mod time;
+mod timer;
 mod update;
 
 pub use app::*;
+pub use events::*;
 pub use from_app::*;
 pub use globals::*;
 #[allow(unused_imports, unreachable_pub)]
 pub use platform::*;
 pub use state::*;
+pub use state_stack::*;
+pub use throttle::*;
+pub use time::*;
+pub use timer::*;
 pub use update::*;
 
 /// Defines the main function of a Modor application.
 ///
 /// This macro ensures to have the same code for all platforms supported by Modor.
 ///
+/// # Arguments
+///
+/// - `log_level = "<level>"`: initializes logging with the given minimum level before running the
+///     function, so that logs emitted before the app creation are correctly filtered.
+///     Allowed levels are `"error"`, `"warn"`, `"info"`, `"debug"` and `"trace"`.
+///     The level is then overridden by [`App::new`].
+///
 /// # Examples
 ///
 /// ```rust
 /// # use modor::*;
 /// # use log::*;
 /// #
-/// #[modor::main]
+/// #[modor::main(log_level = "info")]
 /// fn my_main() {
 ///     let mut app = App::new::<Root>(Level::Info);
 ///     app.update();
@@ -101,14 +119,27 @@ pub use modor_derive::main;
 /// - `wasm`
 /// - `windows`
 ///
-/// It is also possible to parametrize the test using `cases(...)` argument, that accepts key-value
-/// pairs, where key is a test suffix and value is a string containing the arguments to pass to
-/// the test method.
+/// It is also possible to parametrize the test using `cases(...)` argument, where each case has a
+/// test suffix and one of the following forms:
+/// - `suffix = "arg1, arg2"`: a string containing the arguments to pass to the test method.
+/// - `suffix = value`: a single typed argument to pass to the test method (e.g. `suffix = 42`).
+/// - `suffix(param1 = value1, param2 = value2)`: typed arguments bound to the test method
+///   parameters by name, in any order.
+///
+/// Like with `#[test]`, the test function can return a [`Result`]. In this case, each case fails
+/// if it returns an error.
+///
+/// A `timeout = "..."` argument can be passed to make the test fail if it runs longer than the
+/// specified duration (e.g. `"500ms"` or `"5s"`). In this case, the test is run in a separate
+/// thread, so the arguments passed with `cases(...)` are moved into this thread and must implement
+/// [`Send`] and be `'static` (e.g. a string literal can be passed as `&str`, but not a reference
+/// to a temporary value).
 ///
 /// # Platform-specific
 ///
 /// - Web: function is annotated with `#[wasm_bindgen_test::wasm_bindgen_test]` instead of
-///   `#[test]`.
+///   `#[test]`. The `timeout` argument is ignored, as the test cannot be run in a separate
+///   thread.
 ///
 /// # Examples
 ///
@@ -121,6 +152,12 @@ pub use modor_derive::main;
 ///
 /// #[modor::test(cases(zero = "0, false", one = "1, false", failure = "100, true"))]
 /// fn run_parametrized(number: u32, failure: bool) { }
+///
+/// #[modor::test(cases(zero(number = 0, failure = false), failure(failure = true, number = 100)))]
+/// fn run_parametrized_with_named_args(number: u32, failure: bool) { }
+///
+/// #[modor::test(timeout = "5s")]
+/// fn run_with_timeout() { }
 /// ```
 pub use modor_derive::test;
 
@@ -128,15 +165,28 @@ pub use modor_derive::test;
 ///
 /// Both structs and enums are supported.
 ///
+/// Generic types are supported: the trait is implemented for all generic parameters for which
+/// the type implements [`FromApp`].
+///
+/// The `#[state(init = method)]` attribute can be applied on the type so that
+/// [`State::init`] calls `method(&mut self, app: &mut App)`.
+///
 /// # Examples
 ///
 /// ```rust
 /// # use modor::*;
 /// #
 /// #[derive(Default, State)]
+/// #[state(init = init_value)]
 /// struct Root {
 ///     value: u32,
 /// }
+///
+/// impl Root {
+///     fn init_value(&mut self, _app: &mut App) {
+///         self.value = 42;
+///     }
+/// }
 /// ```
 pub use modor_derive::State;
 
@@ -156,11 +206,20 @@ pub use modor_derive::State;
 /// ```
 pub use modor_derive::Global;
 
-/// Generates builder methods for a `struct` with named fields.
+/// Generates builder methods for a `struct`.
+///
+/// For tuple structs, the builder methods are named after the field index (e.g. `with_0`).
 ///
 /// The following attributes can be applied on the `struct` fields:
 /// - `#[builder(form(value))]`: generates a builder method that replaces the value.
 /// - `#[builder(form(closure))]`: generates a builder method that modifies the value.
+/// - `#[builder(form(option))]`: on a field of type `Option<T>`, generates a builder method
+///     `with_<field>` that sets the value wrapped in `Some`, and a builder method
+///     `without_<field>` that sets the value to `None`.
+///
+/// The `#[builder(mut)]` attribute can be applied on the `struct` to also generate, for each
+/// builder method, a setter method taking `&mut self` instead of `self`. The setter methods are
+/// prefixed by `set_` instead of `with_`, and by `unset_` instead of `without_`.
 ///
 /// # Examples
 ///
@@ -220,6 +279,26 @@ pub use modor_derive::Builder;
 ///
 /// - [`Instant`](std::time::Instant) type is initialized with
 ///   [`Instant::now`](std::time::Instant::now) method.
+/// - A field with `#[from_app(with = f)]` attribute is initialized with `f(app)`, where `f` is a
+///   function or a closure taking the [`App`] as parameter.
+///
+/// # Examples
+///
+/// ```rust
+/// # use modor::*;
+/// #
+/// #[derive(FromApp)]
+/// struct Pool {
+///     #[from_app(with = |_| Vec::with_capacity(100))]
+///     items: Vec<u32>,
+///     #[from_app(with = default_size)]
+///     size: usize,
+/// }
+///
+/// fn default_size(app: &mut App) -> usize {
+///     42
+/// }
+/// ```
 pub use modor_derive::FromApp;
 
 /// Generates an updater struct.
@@ -228,7 +307,8 @@ pub use modor_derive::FromApp;
 /// [`Update`].
 ///
 /// Generally, an `apply` method should be manually added for the type `<type name>Updater`
-/// to apply the updates, as it is not generated by this macro.
+/// to apply the updates, as it is not generated by this macro unless the `#[updater(apply)]`
+/// attribute is used.
 ///
 /// # Attributes
 ///
@@ -250,6 +330,34 @@ pub use modor_derive::FromApp;
 ///
 /// The inner type of the field will be taken (i.e. the first generic type).
 ///
+/// This attribute must be combined with `field` and/or `for_field`.
+///
+/// ## `#[updater(tracked)]`
+///
+/// On the struct, generates a struct `<type name>Changes` with a `bool` field for each updated
+/// field, and a method `fn apply_tracked(&mut self, value: &mut <type name>) ->
+/// <type name>Changes` that applies the updates with [`Update::apply_checked`] and returns which
+/// fields have changed.
+///
+/// Fields with `#[updater(inner_type)]` or `#[updater(skip_apply)]` attribute are ignored by
+/// this method.
+///
+/// ## `#[updater(apply)]`
+///
+/// On the struct, generates a method `fn apply(self, value: &mut <type name>)` that applies the
+/// updates with [`Update::apply_checked`].
+///
+/// An additional `#[updater(on_update = f)]` attribute can be added on the struct so that
+/// `f(value)` is called after the updates are applied if at least one field has changed.
+///
+/// Fields with `#[updater(inner_type)]` or `#[updater(skip_apply)]` attribute are ignored by
+/// this method.
+///
+/// ## `#[updater(skip_apply)]`
+///
+/// On a field, ignores the field in the methods generated by `#[updater(tracked)]` and
+/// `#[updater(apply)]` attributes, so that custom logic can be used to apply its update.
+///
 /// # Examples
 ///
 /// ```rust
@@ -0,0 +1,81 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::sync::{Once, RwLock};
+
+static LOGGER_INIT: Once = Once::new();
+static LOG_FILTER: RwLock<LogFilter> = RwLock::new(LogFilter {
+    default_level: LevelFilter::Trace,
+    target_levels: Vec::new(),
+});
+
+pub(crate) fn init(level: Level, create_logger: impl FnOnce() -> Box<dyn Log>) {
+    LOGGER_INIT.call_once(|| {
+        let logger = FilteredLogger {
+            inner: create_logger(),
+        };
+        let _ = log::set_logger(Box::leak(Box::new(logger)));
+    });
+    let mut filter = LOG_FILTER.write().expect("cannot lock log filter");
+    filter.default_level = level.to_level_filter();
+    filter.refresh_max_level();
+}
+
+pub(crate) fn set_target_level(target: String, level: Level) {
+    let mut filter = LOG_FILTER.write().expect("cannot lock log filter");
+    filter
+        .target_levels
+        .retain(|(filtered_target, _)| filtered_target != &target);
+    filter.target_levels.push((target, level.to_level_filter()));
+    filter.refresh_max_level();
+}
+
+#[derive(Debug)]
+struct LogFilter {
+    default_level: LevelFilter,
+    target_levels: Vec<(String, LevelFilter)>,
+}
+
+impl LogFilter {
+    fn level(&self, target: &str) -> LevelFilter {
+        self.target_levels
+            .iter()
+            .filter(|(filtered_target, _)| Self::is_matching(target, filtered_target))
+            .max_by_key(|(filtered_target, _)| filtered_target.len())
+            .map_or(self.default_level, |(_, level)| *level)
+    }
+
+    fn is_matching(target: &str, filtered_target: &str) -> bool {
+        target
+            .strip_prefix(filtered_target)
+            .is_some_and(|suffix| suffix.is_empty() || suffix.starts_with("::"))
+    }
+
+    fn refresh_max_level(&self) {
+        let max_level = self
+            .target_levels
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default_level, Ord::max);
+        log::set_max_level(max_level);
+    }
+}
+
+struct FilteredLogger {
+    inner: Box<dyn Log>,
+}
+
+impl Log for FilteredLogger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        let filter = LOG_FILTER.read().expect("cannot lock log filter");
+        metadata.level() <= filter.level(metadata.target()) && self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if self.enabled(record.metadata()) {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
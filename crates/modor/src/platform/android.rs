@@ -1,12 +1,14 @@
-use android_logger::Config;
+use android_logger::{AndroidLogger, Config};
 use log::{Level, LevelFilter};
 use std::sync::OnceLock;
 
 #[doc(hidden)]
 pub static ANDROID_APP: OnceLock<android_activity::AndroidApp> = OnceLock::new();
 
-pub(crate) fn init_logging(level: Level) {
-    let config = Config::default().with_max_level(LevelFilter::Trace); // allow all levels at compile time
-    android_logger::init_once(config);
-    log::set_max_level(level.to_level_filter());
+#[doc(hidden)]
+pub fn init_logging(level: Level) {
+    crate::logging::init(level, || {
+        let config = Config::default().with_max_level(LevelFilter::Trace); // allow all levels at compile time
+        Box::new(AndroidLogger::new(config))
+    });
 }
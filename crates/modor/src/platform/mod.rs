@@ -6,3 +6,6 @@ include!("wasm.rs");
 
 #[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
 include!("not_android_wasm.rs");
+
+#[cfg(not(target_arch = "wasm32"))]
+include!("not_wasm.rs");
@@ -0,0 +1,24 @@
+use std::panic;
+use std::sync::mpsc;
+use std::sync::mpsc::RecvTimeoutError;
+use std::thread;
+use std::time::Duration;
+
+#[doc(hidden)]
+pub fn run_with_timeout<O>(timeout: Duration, f: impl FnOnce() -> O + Send + 'static) -> O
+where
+    O: Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        let output = f();
+        let _ = sender.send(());
+        output
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(()) | Err(RecvTimeoutError::Disconnected) => {
+            handle.join().unwrap_or_else(|e| panic::resume_unwind(e))
+        }
+        Err(RecvTimeoutError::Timeout) => panic!("test has exceeded timeout of {timeout:?}"),
+    }
+}
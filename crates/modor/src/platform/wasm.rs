@@ -1,7 +1,27 @@
-use log::Level;
+use log::{Level, Log, Metadata, Record};
 use std::panic;
 
-pub(crate) fn init_logging(level: Level) {
+#[doc(hidden)]
+pub fn init_logging(level: Level) {
     panic::set_hook(Box::new(console_error_panic_hook::hook));
-    let _ = console_log::init_with_level(level);
+    crate::logging::init(level, || Box::new(ConsoleLogger));
+}
+
+#[doc(hidden)]
+pub fn run_with_timeout<O>(_timeout: std::time::Duration, f: impl FnOnce() -> O) -> O {
+    f()
+}
+
+struct ConsoleLogger;
+
+impl Log for ConsoleLogger {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        console_log::log(record);
+    }
+
+    fn flush(&self) {}
 }
@@ -0,0 +1,232 @@
+use crate::{App, State};
+use log::debug;
+use std::any::Any;
+use std::fmt::{Debug, Formatter};
+use std::{any, fmt, mem};
+
+/// A stack of [`StackState`]s used to manage transitions between game phases.
+///
+/// Transitions requested with [`StateStack::push`], [`StateStack::pop`] and
+/// [`StateStack::replace`] are applied during the next update of the stack.
+///
+/// During update, transitions are applied before the stacked states are updated.
+///
+/// # Examples
+///
+/// ```rust
+/// # use modor::*;
+/// #
+/// #[derive(Default, State)]
+/// struct Root;
+///
+/// impl Root {
+///     fn start(app: &mut App) {
+///         app.get_mut::<StateStack>().push(Menu);
+///     }
+/// }
+///
+/// struct Menu;
+///
+/// impl StackState for Menu {
+///     fn update(&mut self, app: &mut App) -> StateTransition {
+///         let is_play_pressed = true;
+///         if is_play_pressed {
+///             StateTransition::Replace(Box::new(Playing))
+///         } else {
+///             StateTransition::None
+///         }
+///     }
+/// }
+///
+/// struct Playing;
+///
+/// impl StackState for Playing {
+///     fn on_enter(&mut self, app: &mut App) {
+///         println!("Game started");
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct StateStack {
+    /// Which stacked states are updated.
+    ///
+    /// Default is [`StackUpdateMode::Top`].
+    pub update_mode: StackUpdateMode,
+    states: Vec<Box<dyn StackState>>,
+    pending_transitions: Vec<StateTransition>,
+}
+
+// coverage: off (not necessary to test Debug impl)
+impl Debug for StateStack {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StateStack")
+            .field("update_mode", &self.update_mode)
+            .field("len", &self.states.len())
+            .finish_non_exhaustive()
+    }
+}
+// coverage: on
+
+impl State for StateStack {
+    fn update(&mut self, app: &mut App) {
+        for transition in mem::take(&mut self.pending_transitions) {
+            self.apply(app, transition);
+        }
+        let transitions: Vec<_> = match self.update_mode {
+            StackUpdateMode::Top => self
+                .states
+                .last_mut()
+                .map(|state| state.update(app))
+                .into_iter()
+                .collect(),
+            StackUpdateMode::All => self
+                .states
+                .iter_mut()
+                .map(|state| state.update(app))
+                .collect(),
+        };
+        for transition in transitions {
+            self.apply(app, transition);
+        }
+    }
+}
+
+impl StateStack {
+    /// Returns the number of stacked states.
+    ///
+    /// Pending transitions are not taken into account.
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns whether the stack is empty.
+    ///
+    /// Pending transitions are not taken into account.
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Requests to push `state` on top of the stack.
+    pub fn push(&mut self, state: impl StackState) {
+        self.pending_transitions
+            .push(StateTransition::Push(Box::new(state)));
+    }
+
+    /// Requests to remove the top state.
+    ///
+    /// Nothing happens if the stack is empty when the transition is applied.
+    pub fn pop(&mut self) {
+        self.pending_transitions.push(StateTransition::Pop);
+    }
+
+    /// Requests to replace the top state by `state`.
+    ///
+    /// If the stack is empty when the transition is applied, `state` is pushed.
+    pub fn replace(&mut self, state: impl StackState) {
+        self.pending_transitions
+            .push(StateTransition::Replace(Box::new(state)));
+    }
+
+    fn apply(&mut self, app: &mut App, transition: StateTransition) {
+        match transition {
+            StateTransition::None => (),
+            StateTransition::Push(state) => self.push_now(app, state),
+            StateTransition::Pop => self.pop_now(app),
+            StateTransition::Replace(state) => {
+                self.pop_now(app);
+                self.push_now(app, state);
+            }
+        }
+    }
+
+    fn push_now(&mut self, app: &mut App, mut state: Box<dyn StackState>) {
+        debug!("Push state `{}` in state stack", state.name());
+        state.on_enter(app);
+        self.states.push(state);
+    }
+
+    fn pop_now(&mut self, app: &mut App) {
+        if let Some(mut state) = self.states.pop() {
+            debug!("Pop state `{}` from state stack", state.name());
+            state.on_exit(app);
+        } else {
+            debug!("State stack already empty");
+        }
+    }
+}
+
+/// A trait for defining a state managed by a [`StateStack`].
+///
+/// The methods of this trait are run while the [`StateStack`] is borrowed, so the stack cannot be
+/// accessed from them with [`App::get_mut`]. Transitions from a stacked state are instead
+/// requested with the [`StateTransition`] returned by [`StackState::update`].
+///
+/// # Examples
+///
+/// See [`StateStack`].
+pub trait StackState: Any {
+    /// Runs logic when the state is pushed in the stack.
+    #[allow(unused_variables)]
+    fn on_enter(&mut self, app: &mut App) {}
+
+    /// Runs logic when the state is removed from the stack.
+    #[allow(unused_variables)]
+    fn on_exit(&mut self, app: &mut App) {}
+
+    /// Updates the state.
+    ///
+    /// This method is called during each update of the [`StateStack`] if the state is at the top
+    /// of the stack, or for all stacked states if [`StateStack::update_mode`] is
+    /// [`StackUpdateMode::All`].
+    ///
+    /// The returned transition is applied just after the update.
+    #[allow(unused_variables)]
+    fn update(&mut self, app: &mut App) -> StateTransition {
+        StateTransition::None
+    }
+
+    #[doc(hidden)]
+    fn name(&self) -> &'static str {
+        any::type_name::<Self>()
+    }
+}
+
+/// A transition between states of a [`StateStack`].
+///
+/// # Examples
+///
+/// See [`StateStack`].
+#[non_exhaustive]
+pub enum StateTransition {
+    /// No transition.
+    None,
+    /// Push a state on top of the stack.
+    Push(Box<dyn StackState>),
+    /// Remove the top state.
+    Pop,
+    /// Replace the top state.
+    Replace(Box<dyn StackState>),
+}
+
+// coverage: off (not necessary to test Debug impl)
+impl Debug for StateTransition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Push(state) => write!(f, "Push({})", state.name()),
+            Self::Pop => write!(f, "Pop"),
+            Self::Replace(state) => write!(f, "Replace({})", state.name()),
+        }
+    }
+}
+// coverage: on
+
+/// Which states of a [`StateStack`] are updated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum StackUpdateMode {
+    /// Only the top state is updated.
+    #[default]
+    Top,
+    /// All states are updated, from the bottom to the top of the stack.
+    All,
+}
@@ -0,0 +1,74 @@
+use crate::{Timer, TimerMode};
+use std::time::Duration;
+
+/// A helper to run logic at most once per interval.
+///
+/// The time is accumulated manually using [`Throttle::tick`], so that simulated time can be used.
+///
+/// # Examples
+///
+/// ```rust
+/// # use modor::*;
+/// # use std::time::Duration;
+/// #
+/// struct Spawner {
+///     throttle: Throttle,
+///     spawned_count: usize,
+/// }
+///
+/// impl Spawner {
+///     fn new() -> Self {
+///         Self {
+///             throttle: Throttle::new(Duration::from_millis(500)),
+///             spawned_count: 0,
+///         }
+///     }
+///
+///     fn update(&mut self, delta: Duration) {
+///         if self.throttle.tick(delta) {
+///             self.spawned_count += 1;
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Throttle {
+    timer: Timer,
+    is_started: bool,
+}
+
+impl Throttle {
+    /// Creates a new throttle that allows running logic once per `interval`.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            timer: Timer::new(interval, TimerMode::Repeating),
+            is_started: false,
+        }
+    }
+
+    /// Returns the interval.
+    pub fn interval(&self) -> Duration {
+        self.timer.duration()
+    }
+
+    /// Accumulates `delta` time and returns whether the logic should run.
+    ///
+    /// The first call always returns `true` and ignores `delta`.
+    ///
+    /// If the accumulated time exceeds multiple intervals (e.g. during a long frame), `true` is
+    /// returned only once and the missed runs are skipped.
+    pub fn tick(&mut self, delta: Duration) -> bool {
+        if self.is_started {
+            self.timer.tick(delta)
+        } else {
+            self.is_started = true;
+            true
+        }
+    }
+
+    /// Resets the throttle, so that the next call to [`Throttle::tick`] returns `true`.
+    pub fn reset(&mut self) {
+        self.timer.reset();
+        self.is_started = false;
+    }
+}
@@ -0,0 +1,71 @@
+use crate::State;
+use instant::Instant;
+use std::time::Duration;
+
+/// The number of updates performed by the [`App`](crate::App).
+///
+/// The count is incremented at the end of each [`App::update`](crate::App::update).
+///
+/// # Examples
+///
+/// ```
+/// # use modor::*;
+/// #
+/// fn is_first_update(app: &mut App) -> bool {
+///     app.get_mut::<FrameCount>().get() == 0
+/// }
+/// ```
+#[derive(Default, Debug)]
+pub struct FrameCount {
+    pub(crate) count: u64,
+}
+
+impl State for FrameCount {}
+
+impl FrameCount {
+    /// Returns the number of performed updates.
+    pub fn get(&self) -> u64 {
+        self.count
+    }
+}
+
+/// The time elapsed since the creation of the [`App`](crate::App).
+///
+/// The elapsed time is refreshed at the end of each [`App::update`](crate::App::update).
+///
+/// # Examples
+///
+/// ```
+/// # use modor::*;
+/// #
+/// fn print_elapsed_time(app: &mut App) {
+///     println!("Elapsed time: {:?}", app.get_mut::<ElapsedTime>().get());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ElapsedTime {
+    start: Instant,
+    duration: Duration,
+}
+
+impl State for ElapsedTime {}
+
+impl Default for ElapsedTime {
+    fn default() -> Self {
+        Self {
+            start: Instant::now(),
+            duration: Duration::ZERO,
+        }
+    }
+}
+
+impl ElapsedTime {
+    /// Returns the time elapsed between the creation of the app and the end of the last update.
+    pub fn get(&self) -> Duration {
+        self.duration
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        self.duration = self.start.elapsed();
+    }
+}
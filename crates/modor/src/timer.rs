@@ -0,0 +1,136 @@
+use std::time::Duration;
+
+/// A timer that finishes after a given duration.
+///
+/// The time is accumulated manually using [`Timer::tick`], so that simulated time can be used.
+///
+/// # Examples
+///
+/// ```rust
+/// # use modor::*;
+/// # use std::time::Duration;
+/// #
+/// struct Weapon {
+///     cooldown: Timer,
+///     reload: Timer,
+/// }
+///
+/// impl Weapon {
+///     fn new() -> Self {
+///         Self {
+///             cooldown: Timer::new(Duration::from_millis(200), TimerMode::Repeating),
+///             reload: Timer::new(Duration::from_secs(2), TimerMode::Once),
+///         }
+///     }
+///
+///     fn update(&mut self, delta: Duration) {
+///         self.reload.tick(delta);
+///         if self.reload.finished() && self.cooldown.tick(delta) {
+///             println!("Shoot");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Timer {
+    /// Whether a [`TimerMode::Repeating`] timer catches up the missed completions.
+    ///
+    /// If the accumulated time exceeds multiple durations during a tick (e.g. during a long
+    /// frame), the timer finishes only once during this tick. Then:
+    /// - if `false`, the missed completions are skipped.
+    /// - if `true`, the timer finishes once per tick during the next ticks until it has caught up.
+    ///
+    /// Default is `false`.
+    pub is_catching_up: bool,
+    duration: Duration,
+    mode: TimerMode,
+    elapsed: Duration,
+    is_just_finished: bool,
+}
+
+impl Timer {
+    /// Creates a new timer that finishes after `duration`.
+    pub fn new(duration: Duration, mode: TimerMode) -> Self {
+        Self {
+            is_catching_up: false,
+            duration,
+            mode,
+            elapsed: Duration::ZERO,
+            is_just_finished: false,
+        }
+    }
+
+    /// Returns the duration.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the mode.
+    pub fn mode(&self) -> TimerMode {
+        self.mode
+    }
+
+    /// Returns the time elapsed since the timer has been started or has last finished.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Accumulates `delta` time and returns whether the timer has finished during this tick.
+    pub fn tick(&mut self, delta: Duration) -> bool {
+        match self.mode {
+            TimerMode::Once => {
+                let was_finished = self.elapsed >= self.duration;
+                self.elapsed = (self.elapsed + delta).min(self.duration);
+                self.is_just_finished = !was_finished && self.elapsed >= self.duration;
+            }
+            TimerMode::Repeating => {
+                self.elapsed += delta;
+                self.is_just_finished = self.elapsed >= self.duration;
+                if self.is_just_finished {
+                    self.elapsed = if self.is_catching_up {
+                        self.elapsed.saturating_sub(self.duration)
+                    } else {
+                        let remaining_nanos =
+                            self.elapsed.as_nanos() % self.duration.as_nanos().max(1);
+                        Duration::from_nanos(u64::try_from(remaining_nanos).unwrap_or(u64::MAX))
+                    };
+                }
+            }
+        }
+        self.is_just_finished
+    }
+
+    /// Returns whether the timer is finished.
+    ///
+    /// For a [`TimerMode::Once`] timer, `true` is returned from the moment the timer finishes
+    /// until it is reset.
+    ///
+    /// For a [`TimerMode::Repeating`] timer, `true` is returned only if the timer has finished
+    /// during the last tick, like [`Timer::just_finished`].
+    pub fn finished(&self) -> bool {
+        match self.mode {
+            TimerMode::Once => self.elapsed >= self.duration,
+            TimerMode::Repeating => self.is_just_finished,
+        }
+    }
+
+    /// Returns whether the timer has finished during the last tick.
+    pub fn just_finished(&self) -> bool {
+        self.is_just_finished
+    }
+
+    /// Resets the timer, so that it restarts from zero.
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.is_just_finished = false;
+    }
+}
+
+/// The mode of a [`Timer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimerMode {
+    /// The timer finishes once and stays finished until it is reset.
+    Once,
+    /// The timer restarts each time it finishes.
+    Repeating,
+}
@@ -1,5 +1,8 @@
 use log::Level;
 use modor::{App, FromApp, State, StateHandle};
+use std::any;
+use std::thread;
+use std::time::Duration;
 
 #[modor::test]
 fn create_state() {
@@ -39,14 +42,95 @@ fn take_state_handle() {
     assert_eq!(result, 42);
 }
 
+#[modor::test]
+fn get_existing_state() {
+    let mut app = App::new::<Root>(Level::Info);
+    if let Some(root) = app.try_get_mut::<Root>() {
+        root.value = 10;
+    }
+    assert_eq!(app.try_get::<Root>().map(|root| root.value), Some(10));
+    app.update();
+    let last_updated_value = app
+        .try_get::<Root>()
+        .and_then(|root| root.last_updated_value);
+    assert_eq!(last_updated_value, Some(10));
+}
+
+#[modor::test]
+fn get_missing_state() {
+    let mut app = App::new::<Root>(Level::Info);
+    assert!(app.try_get::<Missing>().is_none());
+    assert!(app.try_get_mut::<Missing>().is_none());
+    assert!(app.try_get::<Missing>().is_none());
+}
+
+#[modor::test]
+fn run_for_count() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.run_for(3);
+    assert_eq!(app.get_mut::<Root>().update_count, 3);
+}
+
+#[modor::test]
+fn run_until_predicate_satisfied() {
+    let mut app = App::new::<Root>(Level::Info);
+    let is_satisfied = app.run_until(10, |app| app.get_mut::<Root>().update_count == 4);
+    assert!(is_satisfied);
+    assert_eq!(app.get_mut::<Root>().update_count, 4);
+}
+
+#[modor::test]
+fn run_until_max_updates_reached() {
+    let mut app = App::new::<Root>(Level::Info);
+    let is_satisfied = app.run_until(5, |_| false);
+    assert!(!is_satisfied);
+    assert_eq!(app.get_mut::<Root>().update_count, 5);
+}
+
+#[modor::test(disabled(wasm))]
+fn profile_state_updates() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.create::<Slow>();
+    app.set_profiling(true);
+    app.update();
+    let profile = app.last_update_profile();
+    assert!(profile
+        .iter()
+        .any(|(name, _)| *name == any::type_name::<Root>()));
+    let slow_duration = profile
+        .iter()
+        .find(|(name, _)| *name == any::type_name::<Slow>())
+        .map(|(_, duration)| *duration);
+    assert!(slow_duration.is_some_and(|duration| duration >= Duration::from_millis(10)));
+}
+
+#[modor::test]
+fn disable_state_update_profiling() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.update();
+    assert!(app.last_update_profile().is_empty());
+    app.set_profiling(true);
+    app.update();
+    assert!(!app.last_update_profile().is_empty());
+    app.set_profiling(false);
+    app.update();
+    assert!(app.last_update_profile().is_empty());
+}
+
 struct Root {
     value: usize,
+    update_count: u32,
+    last_updated_value: Option<usize>,
 }
 
 impl FromApp for Root {
     fn from_app(app: &mut App) -> Self {
         app.get_mut::<Counter>().value += 1;
-        Self { value: 0 }
+        Self {
+            value: 0,
+            update_count: 0,
+            last_updated_value: None,
+        }
     }
 }
 
@@ -54,9 +138,26 @@ impl State for Root {
     fn init(&mut self, _app: &mut App) {
         self.value = 42;
     }
+
+    fn update(&mut self, _app: &mut App) {
+        self.update_count += 1;
+        self.last_updated_value = Some(self.value);
+    }
 }
 
 #[derive(Default, State)]
 struct Counter {
     value: usize,
 }
+
+#[derive(Default, State)]
+struct Missing;
+
+#[derive(Default)]
+struct Slow;
+
+impl State for Slow {
+    fn update(&mut self, _app: &mut App) {
+        thread::sleep(Duration::from_millis(10));
+    }
+}
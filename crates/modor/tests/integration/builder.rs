@@ -10,6 +10,40 @@ fn use_builder_methods() {
     assert_eq!(built.ignored, 0);
 }
 
+#[modor::test]
+fn use_option_builder_methods() {
+    let built = OptionTest::default().with_value(42).with_label("a".into());
+    assert_eq!(built.value, Some(42));
+    assert_eq!(built.label.as_deref(), Some("a"));
+    let built = built.without_value();
+    assert_eq!(built.value, None);
+    assert_eq!(built.label.as_deref(), Some("a"));
+}
+
+#[modor::test]
+fn use_setter_methods() {
+    let mut built = MutTest::default();
+    built.set_value(42);
+    built.set_closure(|vec| vec.push(10));
+    built.set_option(5);
+    assert_eq!(built.value, 42);
+    assert_eq!(built.closure, [10]);
+    assert_eq!(built.option, Some(5));
+    built.unset_option();
+    assert_eq!(built.option, None);
+    let built = built.with_value(43).with_option(6);
+    assert_eq!(built.value, 43);
+    assert_eq!(built.option, Some(6));
+}
+
+#[modor::test]
+fn use_tuple_builder_methods() {
+    let built = TupleTest::default().with_0(42).with_1(|vec| vec.push(10));
+    assert_eq!(built.0, 42);
+    assert_eq!(built.1, [10]);
+    assert_eq!(built.2, 0);
+}
+
 #[derive(Default, Builder)]
 struct Test {
     #[builder(form(value))]
@@ -18,3 +52,29 @@ struct Test {
     closure: Vec<i64>,
     ignored: u8,
 }
+
+#[derive(Default, Builder)]
+struct OptionTest {
+    #[builder(form(option))]
+    value: Option<u32>,
+    #[builder(form(option))]
+    label: Option<String>,
+}
+
+#[derive(Default, Builder)]
+#[builder(mut)]
+struct MutTest {
+    #[builder(form(value))]
+    value: u32,
+    #[builder(form(closure))]
+    closure: Vec<i64>,
+    #[builder(form(option))]
+    option: Option<u32>,
+}
+
+#[derive(Default, Builder)]
+struct TupleTest(
+    #[builder(form(value))] u32,
+    #[builder(form(closure))] Vec<i64>,
+    u8,
+);
@@ -0,0 +1,62 @@
+use log::Level;
+use modor::{App, EventReader, Events, FromApp, State};
+
+#[modor::test]
+fn read_events_with_multiple_readers() {
+    let mut app = App::new::<Root>(Level::Info);
+    let mut reader1 = EventReader::<Event>::from_app(&mut app);
+    let mut reader2 = EventReader::<Event>::from_app(&mut app);
+    app.send_event(Event(1));
+    app.send_event(Event(2));
+    assert_eq!(reader1.read(&mut app), [&Event(1), &Event(2)]);
+    assert_eq!(reader1.read(&mut app), Vec::<&Event>::new());
+    app.send_event(Event(3));
+    assert_eq!(reader1.read(&mut app), [&Event(3)]);
+    assert_eq!(reader2.read(&mut app), [&Event(1), &Event(2), &Event(3)]);
+    assert_eq!(reader2.read(&mut app), Vec::<&Event>::new());
+}
+
+#[modor::test]
+fn read_events_during_update() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.send_event(Event(1));
+    app.update();
+    assert_eq!(app.get_mut::<Root>().received, [1]);
+    app.send_event(Event(2));
+    app.update();
+    app.update();
+    assert_eq!(app.get_mut::<Root>().received, [1, 2]);
+}
+
+#[modor::test]
+fn remove_old_events() {
+    let mut app = App::new::<Root>(Level::Info);
+    let mut reader = EventReader::<Event>::from_app(&mut app);
+    app.get_mut::<Events<Event>>().retention = 3;
+    app.send_event(Event(1));
+    app.update();
+    app.send_event(Event(2));
+    app.update();
+    app.update();
+    assert_eq!(app.get_mut::<Events<Event>>().iter().count(), 1);
+    assert_eq!(reader.read(&mut app), [&Event(2)]);
+    app.update();
+    assert_eq!(app.get_mut::<Events<Event>>().iter().count(), 0);
+}
+
+#[derive(FromApp)]
+struct Root {
+    reader: EventReader<Event>,
+    received: Vec<u32>,
+}
+
+impl State for Root {
+    fn update(&mut self, app: &mut App) {
+        let events = self.reader.read(app);
+        self.received
+            .extend(events.into_iter().map(|event| event.0));
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Event(u32);
@@ -27,6 +27,15 @@ fn create_with_callback() {
     assert_eq!(value.field2, 1);
 }
 
+#[modor::test]
+fn create_struct_with_custom_field_constructors() {
+    let mut app = App::new::<Root>(Level::Info);
+    let value = CustomFields::from_app(&mut app);
+    assert_eq!(value.function.0, 11);
+    assert!(value.closure.capacity() >= 20);
+    assert_eq!(value.default, 0);
+}
+
 #[derive(Default, State)]
 struct Root;
 
@@ -42,6 +51,19 @@ struct UnnamedFields(Integer, u8);
 #[derive(FromApp)]
 struct Unit;
 
+#[derive(FromApp)]
+struct CustomFields {
+    #[from_app(with = incremented_integer)]
+    function: Integer,
+    #[from_app(with = |_| Vec::with_capacity(20))]
+    closure: Vec<u8>,
+    default: u8,
+}
+
+fn incremented_integer(app: &mut App) -> Integer {
+    Integer(Integer::from_app(app).0 + 1)
+}
+
 struct Integer(u32);
 
 impl FromApp for Integer {
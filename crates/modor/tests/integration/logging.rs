@@ -0,0 +1,45 @@
+use log::{log_enabled, Level};
+use modor::App;
+
+#[modor::test]
+fn filter_logs_of_target() {
+    App::new::<Root>(Level::Info);
+    App::set_log_level("filtered_target", Level::Warn);
+    assert!(log_enabled!(target: "filtered_target", Level::Error));
+    assert!(log_enabled!(target: "filtered_target", Level::Warn));
+    assert!(!log_enabled!(target: "filtered_target", Level::Info));
+    assert!(!log_enabled!(target: "filtered_target::child", Level::Info));
+    assert!(log_enabled!(target: "filtered_target_other", Level::Error));
+    assert!(!log_enabled!(target: "filtered_target_other", Level::Trace));
+}
+
+#[modor::test]
+fn enable_logs_of_target_below_default_level() {
+    App::new::<Root>(Level::Info);
+    App::set_log_level("verbose_target", Level::Trace);
+    assert!(log_enabled!(target: "verbose_target", Level::Trace));
+    assert!(log_enabled!(target: "verbose_target::child", Level::Debug));
+    assert!(!log_enabled!(target: "other_target", Level::Trace));
+}
+
+#[modor::test]
+fn use_most_specific_target_level() {
+    App::new::<Root>(Level::Info);
+    App::set_log_level("nested_target", Level::Error);
+    App::set_log_level("nested_target::child", Level::Debug);
+    assert!(!log_enabled!(target: "nested_target", Level::Warn));
+    assert!(log_enabled!(target: "nested_target::child", Level::Debug));
+    assert!(log_enabled!(target: "nested_target::child::grandchild", Level::Debug));
+}
+
+#[modor::test]
+fn override_target_level() {
+    App::new::<Root>(Level::Info);
+    App::set_log_level("overridden_target", Level::Error);
+    App::set_log_level("overridden_target", Level::Warn);
+    assert!(log_enabled!(target: "overridden_target", Level::Warn));
+    assert!(!log_enabled!(target: "overridden_target", Level::Info));
+}
+
+#[derive(Default, modor::State)]
+struct Root;
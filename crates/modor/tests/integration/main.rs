@@ -1,7 +1,14 @@
 pub mod app;
 pub mod builder;
+pub mod events;
 pub mod from_app;
 pub mod globals;
+pub mod logging;
+pub mod state;
+pub mod state_stack;
 pub mod test;
+pub mod throttle;
+pub mod time;
+pub mod timer;
 pub mod update;
 pub mod updater;
@@ -0,0 +1,65 @@
+use log::Level;
+use modor::{App, State};
+
+#[modor::test]
+fn use_generic_state() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.get_mut::<Pool<u32>>().items.push(1);
+    app.get_mut::<Pool<String>>().items.push("a".into());
+    assert_eq!(app.get_mut::<Pool<u32>>().items, [1]);
+    assert_eq!(app.get_mut::<Pool<String>>().items, ["a"]);
+}
+
+#[modor::test]
+fn use_generic_state_with_where_clause() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.get_mut::<Cache<u32>>().value += 1;
+    assert_eq!(app.get_mut::<Cache<u32>>().value, 1);
+}
+
+#[modor::test]
+fn run_init_method() {
+    let mut app = App::new::<InitRoot>(Level::Info);
+    app.run_for(3);
+    let state = app.get_mut::<Initialized>();
+    assert_eq!(state.init_count, 1);
+    assert_eq!(state.update_count, 3);
+}
+
+#[derive(Default, State)]
+struct Root;
+
+#[derive(Default, State)]
+struct Pool<T> {
+    items: Vec<T>,
+}
+
+#[derive(Default, State)]
+struct Cache<T>
+where
+    T: Copy,
+{
+    value: T,
+}
+
+#[derive(Default)]
+struct InitRoot;
+
+impl State for InitRoot {
+    fn update(&mut self, app: &mut App) {
+        app.get_mut::<Initialized>().update_count += 1;
+    }
+}
+
+#[derive(Default, State)]
+#[state(init = init_counter)]
+struct Initialized {
+    init_count: u32,
+    update_count: u32,
+}
+
+impl Initialized {
+    fn init_counter(&mut self, _app: &mut App) {
+        self.init_count += 1;
+    }
+}
@@ -0,0 +1,158 @@
+use log::Level;
+use modor::{App, StackState, StackUpdateMode, State, StateStack, StateTransition};
+
+#[modor::test]
+fn push_states() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.get_mut::<StateStack>().push(Tracked("first"));
+    app.get_mut::<StateStack>().push(Tracked("second"));
+    assert_eq!(app.get_mut::<StateStack>().len(), 0);
+    app.update();
+    assert_eq!(app.get_mut::<StateStack>().len(), 2);
+    assert_eq!(
+        app.get_mut::<Events>().0,
+        ["enter first", "enter second", "update second"]
+    );
+    app.get_mut::<Events>().0.clear();
+    app.update();
+    assert_eq!(app.get_mut::<Events>().0, ["update second"]);
+}
+
+#[modor::test]
+fn pop_states() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.get_mut::<StateStack>().push(Tracked("first"));
+    app.get_mut::<StateStack>().push(Tracked("second"));
+    app.update();
+    app.get_mut::<Events>().0.clear();
+    app.get_mut::<StateStack>().pop();
+    app.update();
+    assert_eq!(app.get_mut::<StateStack>().len(), 1);
+    assert_eq!(app.get_mut::<Events>().0, ["exit second", "update first"]);
+    app.get_mut::<Events>().0.clear();
+    app.get_mut::<StateStack>().pop();
+    app.get_mut::<StateStack>().pop();
+    app.update();
+    assert!(app.get_mut::<StateStack>().is_empty());
+    assert_eq!(app.get_mut::<Events>().0, ["exit first"]);
+}
+
+#[modor::test]
+fn replace_state() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.get_mut::<StateStack>().replace(Tracked("first"));
+    app.update();
+    app.get_mut::<Events>().0.clear();
+    app.get_mut::<StateStack>().replace(Tracked("second"));
+    app.update();
+    assert_eq!(app.get_mut::<StateStack>().len(), 1);
+    assert_eq!(
+        app.get_mut::<Events>().0,
+        ["exit first", "enter second", "update second"]
+    );
+}
+
+#[modor::test]
+fn update_all_states() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.get_mut::<StateStack>().update_mode = StackUpdateMode::All;
+    app.get_mut::<StateStack>().push(Tracked("first"));
+    app.get_mut::<StateStack>().push(Tracked("second"));
+    app.update();
+    app.get_mut::<Events>().0.clear();
+    app.update();
+    assert_eq!(app.get_mut::<Events>().0, ["update first", "update second"]);
+}
+
+#[modor::test]
+fn apply_transition_returned_by_state() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.get_mut::<StateStack>().push(Menu);
+    app.update();
+    assert_eq!(app.get_mut::<StateStack>().len(), 1);
+    assert_eq!(app.get_mut::<Events>().0, ["enter playing"]);
+    app.get_mut::<Events>().0.clear();
+    app.update();
+    assert_eq!(app.get_mut::<Events>().0, ["update playing"]);
+}
+
+#[modor::test]
+fn push_state_returned_by_state() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.get_mut::<StateStack>().push(Pause);
+    app.update();
+    assert_eq!(app.get_mut::<StateStack>().len(), 2);
+    assert_eq!(app.get_mut::<Events>().0, ["enter paused"]);
+}
+
+#[modor::test]
+#[should_panic = "state `modor::state_stack::StateStack` already borrowed"]
+fn access_stack_during_state_update() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.get_mut::<StateStack>().push(PopOnUpdate);
+    app.update();
+}
+
+#[modor::test]
+#[should_panic = "state `modor::state_stack::StateStack` already borrowed"]
+fn access_stack_during_state_enter() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.get_mut::<StateStack>().push(PopOnEnter);
+    app.update();
+}
+
+#[derive(Default, State)]
+struct Root;
+
+#[derive(Default, State)]
+struct Events(Vec<String>);
+
+struct Tracked(&'static str);
+
+impl StackState for Tracked {
+    fn on_enter(&mut self, app: &mut App) {
+        app.get_mut::<Events>().0.push(format!("enter {}", self.0));
+    }
+
+    fn on_exit(&mut self, app: &mut App) {
+        app.get_mut::<Events>().0.push(format!("exit {}", self.0));
+    }
+
+    fn update(&mut self, app: &mut App) -> StateTransition {
+        app.get_mut::<Events>().0.push(format!("update {}", self.0));
+        StateTransition::None
+    }
+}
+
+struct Menu;
+
+impl StackState for Menu {
+    fn update(&mut self, _app: &mut App) -> StateTransition {
+        StateTransition::Replace(Box::new(Tracked("playing")))
+    }
+}
+
+struct Pause;
+
+impl StackState for Pause {
+    fn update(&mut self, _app: &mut App) -> StateTransition {
+        StateTransition::Push(Box::new(Tracked("paused")))
+    }
+}
+
+struct PopOnEnter;
+
+impl StackState for PopOnEnter {
+    fn on_enter(&mut self, app: &mut App) {
+        app.get_mut::<StateStack>().pop();
+    }
+}
+
+struct PopOnUpdate;
+
+impl StackState for PopOnUpdate {
+    fn update(&mut self, app: &mut App) -> StateTransition {
+        app.get_mut::<StateStack>().pop();
+        StateTransition::None
+    }
+}
@@ -1,9 +1,71 @@
+use std::num::ParseIntError;
+
 #[modor::test(cases(one = "1", two = "2"))]
 fn check_test_cases(value: u32) {
     assert!(value == 1 || value == 2);
 }
 
+#[modor::test(cases(one = 1, two = 2))]
+fn check_test_cases_with_typed_values(value: u32) {
+    assert!(value == 1 || value == 2);
+}
+
+#[modor::test(cases(
+    small(value = 1, label = "small", is_small = true),
+    large(label = "large", is_small = false, value = 100)
+))]
+fn check_test_cases_with_named_args(value: u32, label: &str, is_small: bool) {
+    assert_eq!(value < 10, is_small);
+    assert_eq!(label, if is_small { "small" } else { "large" });
+}
+
+#[modor::test(cases(zero = "\"0\"", ten = "\"10\""))]
+fn check_test_cases_returning_result(value: &str) -> Result<(), ParseIntError> {
+    assert!(value.parse::<u32>()? <= 10);
+    Ok(())
+}
+
+// cases are not run as tests because the invalid one fails, they are checked by the next test
+#[modor::test(
+    disabled(android, linux, macos, wasm, windows),
+    cases(valid = "\"1\"", invalid = "\"a\"")
+)]
+fn check_test_cases_returning_error(value: &str) -> Result<(), ParseIntError> {
+    assert_eq!(value.parse::<u32>()?, 1);
+    Ok(())
+}
+
+#[modor::test]
+fn check_test_case_result_propagated() {
+    assert_eq!(check_test_cases_returning_error_valid(), Ok(()));
+    assert!(check_test_cases_returning_error_invalid().is_err());
+}
+
 #[modor::test(disabled(wasm))]
 fn check_test_disabled_platforms() {
     // do nothing, just ensure it compiles
 }
+
+#[modor::test(timeout = "5s")]
+fn check_test_timeout_not_reached() {
+    // do nothing, just ensure the test passes
+}
+
+#[modor::test(disabled(wasm), cases(one = "1", two = "2"), timeout = "5s")]
+fn check_test_cases_with_timeout(value: u32) {
+    assert!(value == 1 || value == 2);
+}
+
+#[modor::test(disabled(wasm), timeout = "50ms")]
+#[should_panic = "test has exceeded timeout of 50ms"]
+fn check_test_timeout_reached() {
+    std::thread::sleep(std::time::Duration::from_secs(1));
+}
+
+#[modor::test(disabled(wasm), timeout = "100ms")]
+#[should_panic = "test has exceeded timeout of 100ms"]
+#[ignore = "infinite loop keeps running in background after the test ends"]
+#[allow(clippy::empty_loop)]
+fn check_test_timeout_reached_with_infinite_loop() {
+    loop {}
+}
@@ -0,0 +1,39 @@
+use modor::Throttle;
+use std::time::Duration;
+
+#[modor::test]
+fn run_throttled_logic() {
+    let mut throttle = Throttle::new(Duration::from_millis(100));
+    let run_count = (0..100)
+        .filter(|_| throttle.tick(Duration::from_millis(10)))
+        .count();
+    assert_eq!(run_count, 10);
+}
+
+#[modor::test]
+fn run_first_tick_immediately() {
+    let mut throttle = Throttle::new(Duration::from_secs(10));
+    assert!(throttle.tick(Duration::ZERO));
+    assert!(!throttle.tick(Duration::from_secs(5)));
+    assert!(throttle.tick(Duration::from_secs(5)));
+}
+
+#[modor::test]
+fn skip_missed_runs() {
+    let mut throttle = Throttle::new(Duration::from_millis(100));
+    assert!(throttle.tick(Duration::ZERO));
+    assert!(throttle.tick(Duration::from_millis(350)));
+    assert!(!throttle.tick(Duration::from_millis(40)));
+    assert!(throttle.tick(Duration::from_millis(10)));
+}
+
+#[modor::test]
+fn reset_throttle() {
+    let mut throttle = Throttle::new(Duration::from_millis(100));
+    assert!(throttle.tick(Duration::ZERO));
+    assert!(!throttle.tick(Duration::from_millis(50)));
+    throttle.reset();
+    assert!(throttle.tick(Duration::ZERO));
+    assert!(!throttle.tick(Duration::from_millis(90)));
+    assert_eq!(throttle.interval(), Duration::from_millis(100));
+}
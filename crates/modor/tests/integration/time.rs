@@ -0,0 +1,39 @@
+use log::Level;
+use modor::{App, ElapsedTime, FrameCount, State};
+use std::thread;
+use std::time::Duration;
+
+#[modor::test]
+fn count_frames() {
+    let mut app = App::new::<Root>(Level::Info);
+    assert_eq!(app.get_mut::<FrameCount>().get(), 0);
+    app.update();
+    assert_eq!(app.get_mut::<FrameCount>().get(), 1);
+    app.run_for(3);
+    assert_eq!(app.get_mut::<FrameCount>().get(), 4);
+    assert_eq!(app.get_mut::<Root>().frame_counts, [0, 1, 2, 3]);
+}
+
+#[modor::test(disabled(wasm))]
+fn measure_elapsed_time() {
+    let mut app = App::new::<Root>(Level::Info);
+    assert_eq!(app.get_mut::<ElapsedTime>().get(), Duration::ZERO);
+    thread::sleep(Duration::from_millis(10));
+    app.update();
+    let elapsed = app.get_mut::<ElapsedTime>().get();
+    assert!(elapsed >= Duration::from_millis(10));
+    thread::sleep(Duration::from_millis(10));
+    app.update();
+    assert!(app.get_mut::<ElapsedTime>().get() >= elapsed + Duration::from_millis(10));
+}
+
+#[derive(Default)]
+struct Root {
+    frame_counts: Vec<u64>,
+}
+
+impl State for Root {
+    fn update(&mut self, app: &mut App) {
+        self.frame_counts.push(app.get_mut::<FrameCount>().get());
+    }
+}
@@ -0,0 +1,72 @@
+use modor::{Timer, TimerMode};
+use std::time::Duration;
+
+#[modor::test]
+fn run_once_timer() {
+    let mut timer = Timer::new(Duration::from_millis(100), TimerMode::Once);
+    assert!(!timer.tick(Duration::from_millis(60)));
+    assert!(!timer.finished());
+    assert!(!timer.just_finished());
+    assert!(timer.tick(Duration::from_millis(60)));
+    assert!(timer.finished());
+    assert!(timer.just_finished());
+    assert_eq!(timer.elapsed(), Duration::from_millis(100));
+    assert!(!timer.tick(Duration::from_millis(200)));
+    assert!(timer.finished());
+    assert!(!timer.just_finished());
+}
+
+#[modor::test]
+fn run_repeating_timer() {
+    let mut timer = Timer::new(Duration::from_millis(100), TimerMode::Repeating);
+    let finished_count = (0..100)
+        .filter(|_| timer.tick(Duration::from_millis(10)))
+        .count();
+    assert_eq!(finished_count, 10);
+    assert!(timer.finished());
+    assert!(timer.just_finished());
+    assert!(!timer.tick(Duration::from_millis(10)));
+    assert!(!timer.finished());
+    assert!(!timer.just_finished());
+}
+
+#[modor::test]
+fn skip_missed_completions() {
+    let mut timer = Timer::new(Duration::from_millis(100), TimerMode::Repeating);
+    assert!(timer.tick(Duration::from_millis(350)));
+    assert_eq!(timer.elapsed(), Duration::from_millis(50));
+    assert!(!timer.tick(Duration::from_millis(40)));
+    assert!(timer.tick(Duration::from_millis(10)));
+}
+
+#[modor::test]
+fn catch_up_missed_completions() {
+    let mut timer = Timer::new(Duration::from_millis(100), TimerMode::Repeating);
+    timer.is_catching_up = true;
+    assert!(timer.tick(Duration::from_millis(350)));
+    assert_eq!(timer.elapsed(), Duration::from_millis(250));
+    assert!(timer.tick(Duration::ZERO));
+    assert!(timer.tick(Duration::ZERO));
+    assert!(!timer.tick(Duration::ZERO));
+    assert_eq!(timer.elapsed(), Duration::from_millis(50));
+}
+
+#[modor::test]
+fn reset_timer() {
+    let mut timer = Timer::new(Duration::from_millis(100), TimerMode::Once);
+    timer.tick(Duration::from_millis(150));
+    timer.reset();
+    assert!(!timer.finished());
+    assert!(!timer.just_finished());
+    assert_eq!(timer.elapsed(), Duration::ZERO);
+    assert!(!timer.tick(Duration::from_millis(60)));
+    assert!(timer.tick(Duration::from_millis(60)));
+}
+
+#[modor::test]
+fn retrieve_properties() {
+    let timer = Timer::new(Duration::from_millis(100), TimerMode::Repeating);
+    assert_eq!(timer.duration(), Duration::from_millis(100));
+    assert_eq!(timer.mode(), TimerMode::Repeating);
+    assert!(!timer.is_catching_up);
+}
@@ -28,6 +28,81 @@ fn run_generic_update() {
     assert_eq!(value.array[1], 0);
 }
 
+#[modor::test]
+fn run_tracked_update() {
+    let mut value = TrackedValue::default();
+    let changes = TrackedValueUpdater::default()
+        .integer(10)
+        .for_string(String::pop)
+        .count(0_u32)
+        .additional_integer(20_u16)
+        .apply_tracked(&mut value);
+    assert!(changes.integer);
+    assert!(changes.string);
+    assert!(!changes.count);
+    assert_eq!(value.integer, 10);
+    assert_eq!(value.string, "abc");
+    assert_eq!(value.count, 0);
+}
+
+#[modor::test]
+fn run_tracked_generic_update() {
+    let mut value = TrackedGenericValue::<2, &str>::default();
+    let changes = TrackedGenericValueUpdater::default()
+        .integer(5)
+        .dynamic("abc")
+        .for_array(|array| array[0] = 10)
+        .apply_tracked(&mut value);
+    assert!(!changes.integer);
+    assert!(changes.dynamic);
+    assert!(changes.array);
+    assert_eq!(value.integer, 5);
+    assert_eq!(value.dynamic, "abc");
+    assert_eq!(value.array, [10, 0]);
+}
+
+#[modor::test]
+fn run_tracked_update_without_change() {
+    let mut value = TrackedValue::default();
+    let changes = TrackedValueUpdater::default().apply_tracked(&mut value);
+    assert_eq!(changes, TrackedValueChanges::default());
+}
+
+#[modor::test]
+fn run_generated_apply() {
+    let mut value = AppliedValue::default();
+    AppliedValueUpdater::default()
+        .integer(10)
+        .for_string(String::pop)
+        .skipped(20_u32)
+        .apply(&mut value);
+    assert_eq!(value.integer, 10);
+    assert_eq!(value.string, "abc");
+    assert_eq!(value.skipped, 0);
+    assert_eq!(value.update_count, 1);
+}
+
+#[modor::test]
+fn run_generated_apply_without_change() {
+    let mut value = AppliedValue::default();
+    AppliedValueUpdater::default()
+        .integer(5)
+        .skipped(20_u32)
+        .apply(&mut value);
+    assert_eq!(value.integer, 5);
+    assert_eq!(value.skipped, 0);
+    assert_eq!(value.update_count, 0);
+}
+
+#[modor::test]
+fn run_generated_apply_without_hook() {
+    let mut value = UnhookedValue::default();
+    UnhookedValueUpdater::default()
+        .integer(10)
+        .apply(&mut value);
+    assert_eq!(value.integer, 10);
+}
+
 #[derive(FromApp, State)]
 struct Root;
 
@@ -95,3 +170,87 @@ where
         self.array.apply(&mut value.array);
     }
 }
+
+#[derive(Updater)]
+#[updater(tracked)]
+struct TrackedValue {
+    #[updater(field)]
+    integer: u8,
+    #[updater(for_field)]
+    string: String,
+    #[updater(field)]
+    count: u32,
+    #[updater(inner_type, field)]
+    additional_integer: PhantomData<u16>,
+}
+
+impl Default for TrackedValue {
+    fn default() -> Self {
+        Self {
+            integer: 5,
+            string: "abcd".into(),
+            count: 0,
+            additional_integer: PhantomData,
+        }
+    }
+}
+
+#[derive(Updater)]
+#[updater(tracked)]
+struct TrackedGenericValue<const N: usize, T: 'static> {
+    #[updater(field)]
+    integer: u8,
+    #[updater(field)]
+    dynamic: T,
+    #[updater(for_field)]
+    array: [u16; N],
+}
+
+impl<const N: usize, T> Default for TrackedGenericValue<N, T>
+where
+    T: 'static + Default,
+{
+    fn default() -> Self {
+        Self {
+            integer: 5,
+            dynamic: T::default(),
+            array: [0; N],
+        }
+    }
+}
+
+#[derive(Updater)]
+#[updater(apply, on_update = AppliedValue::on_update)]
+struct AppliedValue {
+    #[updater(field)]
+    integer: u8,
+    #[updater(for_field)]
+    string: String,
+    #[updater(field, skip_apply)]
+    skipped: u32,
+    update_count: u32,
+}
+
+impl Default for AppliedValue {
+    fn default() -> Self {
+        Self {
+            integer: 5,
+            string: "abcd".into(),
+            skipped: 0,
+            update_count: 0,
+        }
+    }
+}
+
+impl AppliedValue {
+    fn on_update(&mut self) {
+        self.update_count += 1;
+    }
+}
+
+#[derive(Default, Updater)]
+#[updater(apply)]
+struct UnhookedValue {
+    #[updater(field)]
+    integer: u8,
+}
@@ -2,10 +2,10 @@
 
 use darling::ast::Data;
 use darling::{FromDeriveInput, FromField, FromMeta};
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
-use syn::__private::Span;
-use syn::{DeriveInput, Type, Visibility};
+use syn::spanned::Spanned;
+use syn::{DeriveInput, Index, Token, Type, Visibility};
 
 use crate::utils;
 
@@ -13,12 +13,9 @@ pub(crate) fn impl_block(input: &DeriveInput) -> Result<TokenStream, TokenStream
     let ident = &input.ident;
     let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
     let parsed = BuilderStruct::from_derive_input(input).map_err(darling::Error::write_errors)?;
-    let builder_fns = builder_fns(&parsed).ok_or_else(|| {
-        utils::error(
-            Span::call_site(),
-            "only structs with named fields are supported",
-        )
-    })?;
+    let is_mut = is_mut(input)?;
+    let builder_fns = builder_fns(&parsed, is_mut)
+        .ok_or_else(|| utils::error(Span::call_site(), "only structs are supported"))??;
     Ok(quote! {
         #[automatically_derived]
         impl #impl_generics #ident #type_generics #where_clause {
@@ -27,46 +24,171 @@ pub(crate) fn impl_block(input: &DeriveInput) -> Result<TokenStream, TokenStream
     })
 }
 
-fn builder_fns(parsed: &BuilderStruct) -> Option<Vec<Option<TokenStream>>> {
+fn is_mut(input: &DeriveInput) -> Result<bool, TokenStream> {
+    let mut is_mut = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("builder"))
+    {
+        attr.parse_args::<Token![mut]>()
+            .map_err(|_| utils::error(attr.span(), "expected `#[builder(mut)]`"))?;
+        is_mut = true;
+    }
+    Ok(is_mut)
+}
+
+fn builder_fns(
+    parsed: &BuilderStruct,
+    is_mut: bool,
+) -> Option<Result<Vec<TokenStream>, TokenStream>> {
     match &parsed.data {
         Data::Enum(_) => None,
-        Data::Struct(data) => data
-            .fields
-            .iter()
-            .map(|field| field.ident.as_ref().map(|ident| builder_fn(field, ident)))
-            .collect(),
+        Data::Struct(data) => Some(
+            data.fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| {
+                    if let Some(ident) = &field.ident {
+                        let name = ident.to_string();
+                        builder_fn(field, &quote! { #ident }, &name, ident.span(), is_mut)
+                    } else {
+                        let index = Index::from(index);
+                        let span = field.ty.span();
+                        let name = index.index.to_string();
+                        builder_fn(field, &quote! { #index }, &name, span, is_mut)
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(|fns| fns.into_iter().flatten().collect()),
+        ),
     }
 }
 
-fn builder_fn(field: &BuilderField, field_ident: &Ident) -> Option<TokenStream> {
-    let vis = &field.vis;
+fn builder_fn(
+    field: &BuilderField,
+    field_member: &TokenStream,
+    field_name: &str,
+    span: Span,
+    is_mut: bool,
+) -> Result<Option<TokenStream>, TokenStream> {
     let type_ = &field.ty;
-    let fn_ident = format_ident!("with_{}", field_ident);
-    let documentation =
-        format!("Returns `self` with a different [`{field_ident}`](#structfield.{field_ident}).",);
-    match &field.form {
-        None => None,
-        Some(BuilderForm::Value) => Some(quote_spanned! {
-            field_ident.span() =>
-            #[doc=#documentation]
+    let arg_ident = field
+        .ident
+        .clone()
+        .unwrap_or_else(|| Ident::new("value", span));
+    let field_doc = if field.ident.is_some() {
+        format!("[`{field_name}`](#structfield.{field_name})")
+    } else {
+        format!("field `{field_name}`")
+    };
+    let methods = match &field.form {
+        None => return Ok(None),
+        Some(BuilderForm::Value) => vec![BuilderMethod {
+            prefixes: ("with", "set"),
+            documentation: format!("a different {field_doc}"),
+            params: quote! { #arg_ident: #type_ },
+            statement: quote! { self.#field_member = #arg_ident; },
+        }],
+        Some(BuilderForm::Closure) => vec![BuilderMethod {
+            prefixes: ("with", "set"),
+            documentation: format!("a different {field_doc}"),
+            params: quote! { f: impl FnOnce(&mut #type_) },
+            statement: quote! { f(&mut self.#field_member); },
+        }],
+        Some(BuilderForm::Option) => {
+            let inner_type = option_inner_type(type_).ok_or_else(|| {
+                utils::error(
+                    type_.span(),
+                    "`form(option)` requires a field of type `Option<T>`",
+                )
+            })?;
+            vec![
+                BuilderMethod {
+                    prefixes: ("with", "set"),
+                    documentation: format!("a different {field_doc}"),
+                    params: quote! { #arg_ident: #inner_type },
+                    statement: quote! {
+                        self.#field_member = ::std::option::Option::Some(#arg_ident);
+                    },
+                },
+                BuilderMethod {
+                    prefixes: ("without", "unset"),
+                    documentation: format!("{field_doc} set to `None`"),
+                    params: quote! {},
+                    statement: quote! { self.#field_member = ::std::option::Option::None; },
+                },
+            ]
+        }
+    };
+    Ok(Some(
+        methods
+            .iter()
+            .map(|method| method.generate(is_mut, &field.vis, field_name, span))
+            .collect(),
+    ))
+}
+
+struct BuilderMethod {
+    prefixes: (&'static str, &'static str),
+    documentation: String,
+    params: TokenStream,
+    statement: TokenStream,
+}
+
+impl BuilderMethod {
+    fn generate(
+        &self,
+        is_mut: bool,
+        vis: &Visibility,
+        field_name: &str,
+        span: Span,
+    ) -> TokenStream {
+        let Self {
+            prefixes: (builder_prefix, setter_prefix),
+            documentation,
+            params,
+            statement,
+        } = self;
+        let builder_ident = format_ident!("{}_{}", builder_prefix, field_name);
+        let builder_doc = format!("Returns `self` with {documentation}.");
+        let builder = quote_spanned! {
+            span =>
+            #[doc=#builder_doc]
             #[allow(dead_code)]
-            #vis fn #fn_ident(mut self, #field_ident: #type_) -> Self {
-                self.#field_ident = #field_ident;
+            #vis fn #builder_ident(mut self, #params) -> Self {
+                #statement
                 self
             }
-        }),
-        Some(BuilderForm::Closure) => Some(quote_spanned! {
-            field_ident.span() =>
-            #[doc=#documentation]
-            #[allow(dead_code)]
-            #vis fn #fn_ident(mut self, f: impl FnOnce(&mut #type_)) -> Self {
-                f(&mut self.#field_ident);
-                self
+        };
+        if is_mut {
+            let setter_ident = format_ident!("{}_{}", setter_prefix, field_name);
+            let setter_doc = format!("Updates `self` with {documentation}.");
+            quote_spanned! {
+                span =>
+                #builder
+
+                #[doc=#setter_doc]
+                #[allow(dead_code)]
+                #vis fn #setter_ident(&mut self, #params) {
+                    #statement
+                }
             }
-        }),
+        } else {
+            builder
+        }
     }
 }
 
+fn option_inner_type(type_: &Type) -> Option<Type> {
+    if let Type::Path(path) = type_ {
+        if path.qself.is_none() && path.path.segments.last()?.ident == "Option" {
+            return utils::first_generic_type(type_.clone());
+        }
+    }
+    None
+}
+
 #[derive(Debug, FromDeriveInput)]
 struct BuilderStruct {
     data: Data<(), BuilderField>,
@@ -86,6 +208,7 @@ struct BuilderField {
 enum BuilderForm {
     Value,
     Closure,
+    Option,
 }
 
 #[cfg(test)]
@@ -101,7 +224,22 @@ mod tests {
 
     #[test]
     fn derive_struct_with_unnamed_fields() -> syn::Result<()> {
-        let input = syn::parse_str::<DeriveInput>("struct Test(u32);")?;
+        let input = syn::parse_str::<DeriveInput>("struct Test(#[builder(form(value))] u32);")?;
+        assert!(super::impl_block(&input).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn derive_with_invalid_struct_attribute() -> syn::Result<()> {
+        let input = syn::parse_str::<DeriveInput>("#[builder(other)] struct Test { field: u32 }")?;
+        assert!(super::impl_block(&input).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn derive_option_form_on_non_option_field() -> syn::Result<()> {
+        let input =
+            syn::parse_str::<DeriveInput>("struct Test { #[builder(form(option))] field: u32 }")?;
         assert!(super::impl_block(&input).is_err());
         Ok(())
     }
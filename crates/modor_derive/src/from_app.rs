@@ -3,7 +3,7 @@ use proc_macro2::{Ident, Literal, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use syn::__private::Span;
 use syn::spanned::Spanned;
-use syn::{Data, DeriveInput, Field, Type};
+use syn::{Data, DeriveInput, Expr, Field, MetaNameValue};
 
 pub(crate) fn impl_block(input: &DeriveInput) -> Result<TokenStream, TokenStream> {
     let crate_ident = utils::crate_ident();
@@ -13,10 +13,11 @@ pub(crate) fn impl_block(input: &DeriveInput) -> Result<TokenStream, TokenStream
         .ok_or_else(|| utils::error(Span::call_site(), "only structs are supported"))?;
     let statements = fields
         .iter()
-        .map(|(ident, ty)| create_statement(&crate_ident, ident, ty));
+        .map(|(ident, field)| create_statement(&crate_ident, ident, field))
+        .collect::<Result<Vec<_>, _>>()?;
     Ok(quote! {
         #[automatically_derived]
-        #[allow(unused_qualifications)]
+        #[allow(unused_qualifications, clippy::redundant_closure_call)]
         impl #impl_generics ::#crate_ident::FromApp for #ident #type_generics #where_clause {
             fn from_app(app: &mut ::#crate_ident::App) -> Self {
                 Self {
@@ -27,13 +28,13 @@ pub(crate) fn impl_block(input: &DeriveInput) -> Result<TokenStream, TokenStream
     })
 }
 
-fn fields(input: &DeriveInput) -> Option<Vec<(TokenStream, &Type)>> {
+fn fields(input: &DeriveInput) -> Option<Vec<(TokenStream, &Field)>> {
     match &input.data {
         Data::Struct(data) => Some(
             data.fields
                 .iter()
                 .enumerate()
-                .map(|(index, field)| (field_ident(index, field), &field.ty))
+                .map(|(index, field)| (field_ident(index, field), field))
                 .collect(),
         ),
         Data::Enum(_) | Data::Union(_) => None,
@@ -48,8 +49,18 @@ fn field_ident(index: usize, field: &Field) -> TokenStream {
     }
 }
 
-fn create_statement(crate_ident: &Ident, ident: &TokenStream, type_: &Type) -> TokenStream {
-    if utils::has_type_name_without_generic(type_, "Instant") {
+fn create_statement(
+    crate_ident: &Ident,
+    ident: &TokenStream,
+    field: &Field,
+) -> Result<TokenStream, TokenStream> {
+    let type_ = &field.ty;
+    Ok(if let Some(constructor) = custom_constructor(field)? {
+        quote_spanned! {
+            constructor.span() =>
+            #ident: (#constructor)(&mut *app),
+        }
+    } else if utils::has_type_name_without_generic(type_, "Instant") {
         quote_spanned! {
             type_.span() =>
             #ident: #type_::now(),
@@ -59,6 +70,27 @@ fn create_statement(crate_ident: &Ident, ident: &TokenStream, type_: &Type) -> T
             type_.span() =>
             #ident: <#type_ as ::#crate_ident::FromApp>::from_app(app),
         }
+    })
+}
+
+fn custom_constructor(field: &Field) -> Result<Option<Expr>, TokenStream> {
+    let Some(attr) = field
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("from_app"))
+    else {
+        return Ok(None);
+    };
+    let arg = attr
+        .parse_args::<MetaNameValue>()
+        .map_err(syn::Error::into_compile_error)?;
+    if arg.path.is_ident("with") {
+        Ok(Some(arg.value))
+    } else {
+        Err(utils::error(
+            arg.path.span(),
+            "expected `with = ...` argument",
+        ))
     }
 }
 
@@ -72,4 +104,19 @@ mod tests {
         assert!(super::impl_block(&input).is_err());
         Ok(())
     }
+
+    #[test]
+    fn derive_with_unknown_field_attribute_arg() -> syn::Result<()> {
+        let input =
+            syn::parse_str::<DeriveInput>("struct Test { #[from_app(other = f)] field: u32 }")?;
+        assert!(super::impl_block(&input).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn derive_with_invalid_field_attribute() -> syn::Result<()> {
+        let input = syn::parse_str::<DeriveInput>("struct Test { #[from_app(with)] field: u32 }")?;
+        assert!(super::impl_block(&input).is_err());
+        Ok(())
+    }
 }
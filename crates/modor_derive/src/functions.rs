@@ -7,18 +7,27 @@ use darling::FromMeta;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned};
 use std::collections::HashMap;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{parse_quote, ItemFn, Meta, Path};
+use syn::{
+    parse_quote, Expr, ExprLit, FnArg, ItemFn, Lit, Meta, MetaNameValue, Pat, PatType, Path,
+    ReturnType, Token, Type,
+};
 
 // coverage: off (cannot be tested)
-pub(crate) fn main_function(function: &ItemFn) -> TokenStream {
+pub(crate) fn main_function(
+    function: &ItemFn,
+    args: TokenStream,
+) -> Result<TokenStream, TokenStream> {
     let crate_ = utils::crate_ident();
     let ident = &function.sig.ident;
-    quote! {
+    let logging_init = main_logging_init(args)?;
+    Ok(quote! {
         #[cfg(target_os = "android")]
         #[no_mangle]
         fn android_main(app: #crate_::android_activity::AndroidApp) {
             let _ = #crate_::ANDROID_APP.get_or_init(move || app);
+            #logging_init
             #function
             #ident();
         }
@@ -30,19 +39,52 @@ pub(crate) fn main_function(function: &ItemFn) -> TokenStream {
 
         #[cfg(not(target_os = "android"))]
         fn main() {
+            #logging_init
             #function
             #ident();
         }
-    }
+    })
 }
 // coverage: on
 
+fn main_logging_init(args: TokenStream) -> Result<TokenStream, TokenStream> {
+    let args =
+        NestedMeta::parse_meta_list(args).map_err(|e| darling::Error::from(e).write_errors())?;
+    let args = MainArgs::from_list(&args).map_err(darling::Error::write_errors)?;
+    let Some(log_level) = &args.log_level else {
+        return Ok(quote! {});
+    };
+    let level = match log_level.to_lowercase().as_str() {
+        "error" => quote! { Error },
+        "warn" => quote! { Warn },
+        "info" => quote! { Info },
+        "debug" => quote! { Debug },
+        "trace" => quote! { Trace },
+        _ => {
+            return Err(utils::error(
+                log_level.span(),
+                "invalid log level, expected one of \"error\", \"warn\", \"info\", \"debug\" \
+                or \"trace\"",
+            ))
+        }
+    };
+    let crate_ = utils::crate_ident();
+    Ok(quote! {
+        ::#crate_::init_logging(::#crate_::log::Level::#level);
+    })
+}
+
 pub(crate) fn test_function(
     function: &ItemFn,
     args: TokenStream,
 ) -> Result<TokenStream, TokenStream> {
     let context = TestContext::new(function, args)?;
     context.check_platform_paths()?;
+    let function = &context.function_with_timeout()?;
+    let context = TestContext {
+        function,
+        ..context
+    };
     if context.args.cases.0.is_empty() {
         Ok(context.annotated_without_cases())
     } else {
@@ -89,6 +131,27 @@ impl<'a> TestContext<'a> {
         Ok(())
     }
 
+    fn function_with_timeout(&self) -> Result<ItemFn, TokenStream> {
+        let mut function = self.function.clone();
+        if let Some(timeout) = &self.args.timeout {
+            let crate_ = utils::crate_ident();
+            let millis = parse_duration_millis(timeout).ok_or_else(|| {
+                utils::error(
+                    timeout.span(),
+                    "invalid timeout, expected a duration like \"500ms\" or \"5s\"",
+                )
+            })?;
+            let block = &function.block;
+            function.block = parse_quote! {{
+                ::#crate_::run_with_timeout(
+                    ::std::time::Duration::from_millis(#millis),
+                    move || #block,
+                )
+            }};
+        }
+        Ok(function)
+    }
+
     fn annotated_without_cases(&self) -> TokenStream {
         let crate_ = utils::crate_ident();
         let function = &self.function;
@@ -108,22 +171,31 @@ impl<'a> TestContext<'a> {
         let crate_ = utils::crate_ident();
         let function = &self.function;
         let main_function_ident = &function.sig.ident;
+        let output = &function.sig.output;
+        let is_result_returned = Self::is_result_type(output);
         let disabled_platform_conditions = self.disabled_platform_conditions();
         let mut test_functions = vec![];
-        for (suffix, params) in &self.args.cases.0 {
-            let span = params.span();
+        for (suffix, args) in &self.args.cases.0 {
+            let span = args.span();
             let function_ident =
                 Ident::new(&format!("{main_function_ident}_{suffix}"), span.span());
-            let params = params
-                .parse::<TokenStream>()
-                .map_err(|_| utils::error(Span::call_site(), "cannot parse test case args"))?
-                .into_iter()
-                .map(|mut token| {
-                    token.set_span(span);
-                    token
-                })
-                .collect::<TokenStream>();
+            let params = self.case_params(args)?;
             let params = quote_spanned! {span => #params};
+            let test_function = if is_result_returned {
+                quote_spanned! {
+                    span =>
+                    fn #function_ident() #output {
+                        #main_function_ident(#params)
+                    }
+                }
+            } else {
+                quote_spanned! {
+                    span =>
+                    fn #function_ident() {
+                        #main_function_ident(#params);
+                    }
+                }
+            };
             test_functions.push(quote_spanned! {
                 span =>
                 #[cfg_attr(any(#(#disabled_platform_conditions),*), allow(unused))]
@@ -132,9 +204,7 @@ impl<'a> TestContext<'a> {
                     all(target_arch = "wasm32", not(any(#(#disabled_platform_conditions),*))),
                     ::#crate_::wasm_bindgen_test::wasm_bindgen_test)
                 ]
-                fn #function_ident() {
-                    #main_function_ident(#params);
-                }
+                #test_function
             });
         }
         Ok(quote! {
@@ -144,6 +214,74 @@ impl<'a> TestContext<'a> {
         })
     }
 
+    fn is_result_type(output: &ReturnType) -> bool {
+        matches!(
+            output,
+            ReturnType::Type(_, type_) if matches!(
+                &**type_,
+                Type::Path(path) if path.path.segments.last().is_some_and(|s| s.ident == "Result")
+            )
+        )
+    }
+
+    fn case_params(&self, args: &TestCaseArgs) -> Result<TokenStream, TokenStream> {
+        match args {
+            TestCaseArgs::Raw(params) => {
+                let span = params.span();
+                Ok(params
+                    .parse::<TokenStream>()
+                    .map_err(|_| utils::error(Span::call_site(), "cannot parse test case args"))?
+                    .into_iter()
+                    .map(|mut token| {
+                        token.set_span(span);
+                        token
+                    })
+                    .collect())
+            }
+            TestCaseArgs::Expr(expr) => Ok(quote! { #expr }),
+            TestCaseArgs::Named(named_args, span) => {
+                let param_names = self.param_names();
+                for (name, _) in named_args {
+                    if !param_names.contains(&name.to_string()) {
+                        return Err(utils::error(
+                            name.span(),
+                            &format!("unknown test parameter `{name}`"),
+                        ));
+                    }
+                }
+                let mut params = vec![];
+                for param_name in param_names {
+                    let (_, expr) = named_args
+                        .iter()
+                        .find(|(name, _)| *name == param_name)
+                        .ok_or_else(|| {
+                            utils::error(*span, &format!("missing test parameter `{param_name}`"))
+                        })?;
+                    params.push(expr);
+                }
+                Ok(quote! { #(#params),* })
+            }
+        }
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        self.function
+            .sig
+            .inputs
+            .iter()
+            .map(|input| match input {
+                FnArg::Typed(PatType { pat, .. }) => {
+                    if let Pat::Ident(ident) = &**pat {
+                        ident.ident.to_string()
+                    } else {
+                        quote! { #pat }.to_string()
+                    }
+                }
+                FnArg::Receiver(_) => "self".into(),
+            })
+            .collect()
+    }
+
     fn disabled_platform_conditions(&self) -> Vec<Meta> {
         return self
             .args
@@ -179,6 +317,17 @@ impl<'a> TestContext<'a> {
     }
 }
 
+fn parse_duration_millis(duration: &str) -> Option<u64> {
+    let duration = duration.trim();
+    if let Some(millis) = duration.strip_suffix("ms") {
+        millis.trim().parse().ok()
+    } else if let Some(secs) = duration.strip_suffix('s') {
+        secs.trim().parse::<u64>().ok()?.checked_mul(1000)
+    } else {
+        None
+    }
+}
+
 fn platform_as_str(platform: &Path) -> String {
     platform.segments[0].ident.to_string()
 }
@@ -189,16 +338,127 @@ struct TestArgs {
     disabled: PathList,
     #[darling(default)]
     cases: TestCases,
+    #[darling(default)]
+    timeout: Option<SpannedValue<String>>,
+}
+
+#[derive(Debug, FromMeta)]
+struct MainArgs {
+    #[darling(default)]
+    log_level: Option<SpannedValue<String>>,
 }
 
-#[derive(Default, FromMeta)]
-struct TestCases(HashMap<String, SpannedValue<String>>);
+#[derive(Default)]
+struct TestCases(Vec<(String, TestCaseArgs)>);
+
+impl FromMeta for TestCases {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| {
+                if let NestedMeta::Meta(Meta::NameValue(meta)) = item {
+                    let args = if let Expr::Lit(ExprLit {
+                        lit: Lit::Str(value),
+                        ..
+                    }) = &meta.value
+                    {
+                        TestCaseArgs::Raw(SpannedValue::new(value.value(), value.span()))
+                    } else {
+                        TestCaseArgs::Expr(meta.value.clone())
+                    };
+                    Ok((path_ident(&meta.path)?.to_string(), args))
+                } else if let NestedMeta::Meta(Meta::List(meta)) = item {
+                    let args = meta
+                        .parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)?
+                        .into_iter()
+                        .map(|arg| Ok((path_ident(&arg.path)?, arg.value)))
+                        .collect::<darling::Result<_>>()?;
+                    Ok((
+                        path_ident(&meta.path)?.to_string(),
+                        TestCaseArgs::Named(args, meta.span()),
+                    ))
+                } else {
+                    Err(darling::Error::unsupported_format("test case").with_span(item))
+                }
+            })
+            .collect::<darling::Result<_>>()
+            .map(Self)
+    }
+}
+
+fn path_ident(path: &Path) -> darling::Result<Ident> {
+    path.get_ident()
+        .cloned()
+        .ok_or_else(|| darling::Error::custom("expected identifier").with_span(path))
+}
+
+enum TestCaseArgs {
+    Raw(SpannedValue<String>),
+    Expr(Expr),
+    Named(Vec<(Ident, Expr)>, Span),
+}
+
+impl TestCaseArgs {
+    fn span(&self) -> Span {
+        match self {
+            Self::Raw(params) => params.span(),
+            Self::Expr(expr) => expr.span(),
+            Self::Named(_, span) => *span,
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use proc_macro2::TokenStream;
     use syn::ItemFn;
 
+    #[test]
+    fn configure_main_log_level() -> syn::Result<()> {
+        let args = syn::parse_str::<TokenStream>("log_level = \"Debug\"")?;
+        let init = super::main_logging_init(args);
+        assert!(init.is_ok_and(|init| init.to_string().contains(":: log :: Level :: Debug")));
+        Ok(())
+    }
+
+    #[test]
+    fn configure_main_without_log_level() -> syn::Result<()> {
+        let args = syn::parse_str::<TokenStream>("")?;
+        let init = super::main_logging_init(args);
+        assert!(init.is_ok_and(|init| init.is_empty()));
+        Ok(())
+    }
+
+    #[test]
+    fn configure_main_with_invalid_log_level() -> syn::Result<()> {
+        let args = syn::parse_str::<TokenStream>("log_level = \"verbose\"")?;
+        assert!(super::main_logging_init(args).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn forward_result_of_test_with_cases() -> syn::Result<()> {
+        let function =
+            syn::parse_str::<ItemFn>("fn test(a: u32) -> Result<(), String> { Ok(()) }")?;
+        let args = syn::parse_str::<TokenStream>("cases(one = 1)")?;
+        let output = super::test_function(&function, args);
+        assert!(output.is_ok_and(|output| output
+            .to_string()
+            .contains("fn test_one () -> Result < () , String > { test (1) }")));
+        Ok(())
+    }
+
+    #[test]
+    fn ignore_non_result_output_of_test_with_cases() -> syn::Result<()> {
+        let function = syn::parse_str::<ItemFn>("fn test(a: usize) -> usize { a }")?;
+        let args = syn::parse_str::<TokenStream>("cases(one = 1)")?;
+        let output = super::test_function(&function, args);
+        assert!(
+            output.is_ok_and(|output| output.to_string().contains("fn test_one () { test (1) ; }"))
+        );
+        Ok(())
+    }
+
     #[test]
     fn exclude_unsupported_platform_from_test_without_cases() -> syn::Result<()> {
         let function = syn::parse_str::<ItemFn>("fn test() {}")?;
@@ -207,6 +467,48 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn use_unknown_named_case_arg() -> syn::Result<()> {
+        let function = syn::parse_str::<ItemFn>("fn test(a: u32) {}")?;
+        let args = syn::parse_str::<TokenStream>("cases(one(a = 1, b = 2))")?;
+        assert!(super::test_function(&function, args).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn use_missing_named_case_arg() -> syn::Result<()> {
+        let function = syn::parse_str::<ItemFn>("fn test(a: u32, b: u32) {}")?;
+        let args = syn::parse_str::<TokenStream>("cases(one(a = 1))")?;
+        assert!(super::test_function(&function, args).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn use_invalid_case_format() -> syn::Result<()> {
+        let function = syn::parse_str::<ItemFn>("fn test(a: u32) {}")?;
+        let args = syn::parse_str::<TokenStream>("cases(one)")?;
+        assert!(super::test_function(&function, args).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn use_invalid_timeout() -> syn::Result<()> {
+        let function = syn::parse_str::<ItemFn>("fn test() {}")?;
+        let args = syn::parse_str::<TokenStream>("timeout = \"5 minutes\"")?;
+        assert!(super::test_function(&function, args).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn use_valid_timeout() -> syn::Result<()> {
+        let function = syn::parse_str::<ItemFn>("fn test() {}")?;
+        let args = syn::parse_str::<TokenStream>("timeout = \"500ms\"")?;
+        assert!(super::test_function(&function, args).is_ok());
+        let args = syn::parse_str::<TokenStream>("timeout = \"5s\"")?;
+        assert!(super::test_function(&function, args).is_ok());
+        Ok(())
+    }
+
     #[test]
     fn exclude_unsupported_platform_from_test_with_cases() -> syn::Result<()> {
         let function = syn::parse_str::<ItemFn>("fn test() {}")?;
@@ -14,9 +14,11 @@ mod utils;
 // coverage: off (cannot be tested)
 #[allow(missing_docs)] // doc available in `modor` crate
 #[proc_macro_attribute]
-pub fn main(_args: TokenStream, item: TokenStream) -> TokenStream {
+pub fn main(args: TokenStream, item: TokenStream) -> TokenStream {
     let function = parse_macro_input!(item as ItemFn);
-    functions::main_function(&function).into()
+    functions::main_function(&function, args.into())
+        .unwrap_or_else(|error| error)
+        .into()
 }
 // coverage: on
 
@@ -31,10 +33,10 @@ pub fn test(args: TokenStream, item: TokenStream) -> TokenStream {
 }
 
 #[allow(missing_docs)] // doc available in `modor` crate
-#[proc_macro_derive(State)]
+#[proc_macro_derive(State, attributes(state))]
 pub fn state_derive(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
-    state::impl_block(&input).into()
+    state::impl_block(&input).unwrap_or_else(Into::into).into()
 }
 
 #[allow(missing_docs)] // doc available in `modor` crate
@@ -54,7 +56,7 @@ pub fn builder_derive(item: TokenStream) -> TokenStream {
 }
 
 #[allow(missing_docs)] // doc available in `modor` crate
-#[proc_macro_derive(FromApp)]
+#[proc_macro_derive(FromApp, attributes(from_app))]
 pub fn from_app_derive(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
     from_app::impl_block(&input)
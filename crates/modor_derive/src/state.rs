@@ -1,14 +1,75 @@
 use crate::utils;
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::DeriveInput;
+use syn::spanned::Spanned;
+use syn::{DeriveInput, Expr, MetaNameValue};
 
-pub(crate) fn impl_block(input: &DeriveInput) -> TokenStream {
+pub(crate) fn impl_block(input: &DeriveInput) -> Result<TokenStream, TokenStream> {
     let crate_ident = utils::crate_ident();
     let ident = &input.ident;
     let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
-    quote! {
+    let where_predicates = where_clause.map(|clause| &clause.predicates);
+    let init_fn = init_method(input)?.map(|method| {
+        quote! {
+            fn init(&mut self, app: &mut ::#crate_ident::App) {
+                Self::#method(self, app);
+            }
+        }
+    });
+    Ok(quote! {
         #[automatically_derived]
-        impl #impl_generics ::#crate_ident::State for #ident #type_generics #where_clause {}
+        impl #impl_generics ::#crate_ident::State for #ident #type_generics
+        where
+            Self: ::#crate_ident::FromApp,
+            #where_predicates
+        {
+            #init_fn
+        }
+    })
+}
+
+fn init_method(input: &DeriveInput) -> Result<Option<Ident>, TokenStream> {
+    let mut method = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("state"))
+    {
+        let arg = attr
+            .parse_args::<MetaNameValue>()
+            .map_err(syn::Error::into_compile_error)?;
+        if !arg.path.is_ident("init") {
+            return Err(utils::error(
+                arg.path.span(),
+                "expected `init = ...` argument",
+            ));
+        }
+        if let Expr::Path(path) = &arg.value {
+            if let Some(ident) = path.path.get_ident() {
+                method = Some(ident.clone());
+                continue;
+            }
+        }
+        return Err(utils::error(arg.value.span(), "expected method name"));
+    }
+    Ok(method)
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::DeriveInput;
+
+    #[test]
+    fn derive_with_unknown_attribute_arg() -> syn::Result<()> {
+        let input = syn::parse_str::<DeriveInput>("#[state(other = setup)] struct Test;")?;
+        assert!(super::impl_block(&input).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn derive_with_invalid_init_method() -> syn::Result<()> {
+        let input = syn::parse_str::<DeriveInput>("#[state(init = 42)] struct Test;")?;
+        assert!(super::impl_block(&input).is_err());
+        Ok(())
     }
 }
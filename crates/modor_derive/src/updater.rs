@@ -5,9 +5,9 @@ use darling::ast::Data;
 use darling::{FromDeriveInput, FromField};
 use proc_macro2::Span;
 use proc_macro2::{Ident, TokenStream};
-use quote::{format_ident, quote, quote_spanned};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::spanned::Spanned;
-use syn::{parse_quote, Attribute, DeriveInput, GenericParam, Generics, Type, Visibility};
+use syn::{parse_quote, Attribute, DeriveInput, GenericParam, Generics, Path, Type, Visibility};
 
 pub(crate) fn impl_block(input: &DeriveInput) -> Result<TokenStream, TokenStream> {
     let crate_ident = utils::crate_ident();
@@ -26,6 +26,12 @@ pub(crate) fn impl_block(input: &DeriveInput) -> Result<TokenStream, TokenStream
     let updater_fns = all_field_fns(input, &crate_ident, &parsed.fields);
     let updater_ident = format_ident!("{}Updater", ident);
     let updater_doc = format!("An updater for [`{ident}`].");
+    let tracked_items = parsed
+        .is_tracked
+        .then(|| tracked_items(input, &crate_ident, &parsed.fields));
+    let apply_items = parsed
+        .is_apply_generated
+        .then(|| apply_items(input, &crate_ident, &parsed));
     Ok(quote! {
         #[doc = #updater_doc]
         #[must_use]
@@ -52,9 +58,139 @@ pub(crate) fn impl_block(input: &DeriveInput) -> Result<TokenStream, TokenStream
         impl #updater_impl_generics #updater_ident #updater_type_generics #updater_where_clause {
             #(#updater_fns)*
         }
+
+        #tracked_items
+
+        #apply_items
     })
 }
 
+fn tracked_items(
+    input: &DeriveInput,
+    crate_ident: &Ident,
+    fields: &[ParsedUpdaterField],
+) -> TokenStream {
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let (_, type_generics, _) = input.generics.split_for_impl();
+    let updater_generics = updater_generics(input);
+    let (updater_impl_generics, updater_type_generics, updater_where_clause) =
+        updater_generics.split_for_impl();
+    let updater_ident = format_ident!("{}Updater", ident);
+    let changes_ident = format_ident!("{}Changes", ident);
+    let changes_doc = format!("The fields of a [`{ident}`] changed by [`{updater_ident}`].");
+    let apply_doc = format!(
+        "Applies the updates on `value`, and returns which fields have changed.\n\n\
+        A field is considered as changed in the same cases as [`Update::apply_checked`]\
+        ({crate_ident}::Update::apply_checked).\n\n\
+        Updates of fields with `#[updater(inner_type)]` or `#[updater(skip_apply)]` attribute \
+        are not applied."
+    );
+    let tracked_fields = applied_fields(fields);
+    let field_idents: Vec<_> = tracked_fields.iter().map(|field| &field.ident).collect();
+    let field_types: Vec<_> = tracked_fields.iter().map(|field| &field.type_).collect();
+    let field_docs = field_idents
+        .iter()
+        .map(|ident| format!("Whether `{ident}` has changed."));
+    let where_predicates = updater_where_clause.map(|clause| &clause.predicates);
+    quote! {
+        #[doc = #changes_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        #[allow(clippy::struct_excessive_bools)]
+        #vis struct #changes_ident {
+            #(#[doc = #field_docs] pub #field_idents: bool,)*
+        }
+
+        #[automatically_derived]
+        #[allow(dead_code)]
+        impl #updater_impl_generics #updater_ident #updater_type_generics
+        where
+            #(#field_types: ::std::cmp::PartialEq,)*
+            #where_predicates
+        {
+            #[doc = #apply_doc]
+            #vis fn apply_tracked(&mut self, value: &mut #ident #type_generics) -> #changes_ident {
+                #changes_ident {
+                    #(
+                        #field_idents: ::#crate_ident::Update::apply_checked(
+                            &mut self.#field_idents,
+                            &mut value.#field_idents,
+                        ),
+                    )*
+                }
+            }
+        }
+    }
+}
+
+fn apply_items(
+    input: &DeriveInput,
+    crate_ident: &Ident,
+    parsed: &ParsedUpdaterStruct,
+) -> TokenStream {
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let (_, type_generics, _) = input.generics.split_for_impl();
+    let updater_generics = updater_generics(input);
+    let (updater_impl_generics, updater_type_generics, updater_where_clause) =
+        updater_generics.split_for_impl();
+    let updater_ident = format_ident!("{}Updater", ident);
+    let applied_fields = applied_fields(&parsed.fields);
+    let field_idents: Vec<_> = applied_fields.iter().map(|field| &field.ident).collect();
+    let field_types: Vec<_> = applied_fields.iter().map(|field| &field.type_).collect();
+    let where_predicates = updater_where_clause.map(|clause| &clause.predicates);
+    let on_update_doc = parsed
+        .on_update
+        .as_ref()
+        .map_or_else(String::new, |on_update| {
+            let on_update = on_update.to_token_stream().to_string().replace(' ', "");
+            format!("\n\nIf at least one field has changed, `{on_update}(value)` is then called.")
+        });
+    let apply_doc = format!(
+        "Applies the updates on `value`.\n\n\
+        A field is considered as changed in the same cases as [`Update::apply_checked`]\
+        ({crate_ident}::Update::apply_checked).\n\n\
+        Updates of fields with `#[updater(inner_type)]` or `#[updater(skip_apply)]` attribute \
+        are not applied.{on_update_doc}"
+    );
+    let on_update_call = parsed.on_update.as_ref().map(|on_update| {
+        quote! {
+            if is_updated {
+                #on_update(value);
+            }
+        }
+    });
+    quote! {
+        #[automatically_derived]
+        #[allow(dead_code)]
+        impl #updater_impl_generics #updater_ident #updater_type_generics
+        where
+            #(#field_types: ::std::cmp::PartialEq,)*
+            #where_predicates
+        {
+            #[doc = #apply_doc]
+            #[allow(unused_mut, unused_variables)]
+            #vis fn apply(mut self, value: &mut #ident #type_generics) {
+                // Bitwise OR operator is used to make sure all `apply_checked` are executed
+                let is_updated = false
+                    #(| ::#crate_ident::Update::apply_checked(
+                        &mut self.#field_idents,
+                        &mut value.#field_idents,
+                    ))*;
+                #on_update_call
+            }
+        }
+    }
+}
+
+fn applied_fields(fields: &[ParsedUpdaterField]) -> Vec<&ParsedUpdaterField> {
+    fields
+        .iter()
+        .filter(|field| !field.has_inner_type && !field.is_apply_skipped)
+        .filter(|field| field.is_field_method_generated || field.is_for_field_method_generated)
+        .collect()
+}
+
 fn updater_generics(input: &DeriveInput) -> Generics {
     let mut updater_generics = input.generics.clone();
     updater_generics
@@ -144,12 +280,20 @@ fn field_fns(
 }
 
 #[derive(Debug, FromDeriveInput)]
+#[darling(attributes(updater))]
 struct UpdaterStruct {
     data: Data<(), UpdaterField>,
+    #[darling(default)]
+    tracked: bool,
+    #[darling(default)]
+    apply: bool,
+    #[darling(default)]
+    on_update: Option<Path>,
 }
 
 #[derive(Debug, FromField)]
 #[darling(attributes(updater), forward_attrs(doc))]
+#[allow(clippy::struct_excessive_bools)]
 struct UpdaterField {
     ident: Option<Ident>,
     ty: Type,
@@ -161,17 +305,28 @@ struct UpdaterField {
     field: bool,
     #[darling(default)]
     for_field: bool,
+    #[darling(default)]
+    skip_apply: bool,
 }
 
 #[derive(Debug)]
 struct ParsedUpdaterStruct {
     fields: Vec<ParsedUpdaterField>,
+    is_tracked: bool,
+    is_apply_generated: bool,
+    on_update: Option<Path>,
 }
 
 impl TryFrom<UpdaterStruct> for ParsedUpdaterStruct {
     type Error = TokenStream;
 
     fn try_from(struct_: UpdaterStruct) -> Result<Self, Self::Error> {
+        if let (Some(on_update), false) = (&struct_.on_update, struct_.apply) {
+            Err(utils::error(
+                on_update.span(),
+                "`on_update` requires `apply` attribute",
+            ))?;
+        }
         Ok(Self {
             fields: match struct_.data {
                 Data::Enum(_) => Err(utils::error(Span::call_site(), "enums are not supported"))?,
@@ -181,18 +336,24 @@ impl TryFrom<UpdaterStruct> for ParsedUpdaterStruct {
                     .map(ParsedUpdaterField::try_from)
                     .collect::<Result<Vec<_>, _>>()?,
             },
+            is_tracked: struct_.tracked,
+            is_apply_generated: struct_.apply,
+            on_update: struct_.on_update,
         })
     }
 }
 
 #[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
 struct ParsedUpdaterField {
     ident: Ident,
     type_: Type,
     vis: Visibility,
     doc_attrs: Vec<Attribute>,
+    has_inner_type: bool,
     is_field_method_generated: bool,
     is_for_field_method_generated: bool,
+    is_apply_skipped: bool,
 }
 
 impl TryFrom<UpdaterField> for ParsedUpdaterField {
@@ -200,13 +361,16 @@ impl TryFrom<UpdaterField> for ParsedUpdaterField {
 
     fn try_from(field: UpdaterField) -> Result<Self, Self::Error> {
         let ident = Self::parse_ident(field.ident)?;
+        Self::check_inner_type_usage(&ident, field.inner_type, field.field, field.for_field)?;
         Ok(Self {
             ident,
             type_: Self::parse_type(field.inner_type, field.ty)?,
             vis: field.vis,
             doc_attrs: Self::parse_doc_attributes(field.attrs),
+            has_inner_type: field.inner_type,
             is_field_method_generated: field.field,
             is_for_field_method_generated: field.for_field,
+            is_apply_skipped: field.skip_apply,
         })
     }
 }
@@ -221,11 +385,31 @@ impl ParsedUpdaterField {
         })
     }
 
+    fn check_inner_type_usage(
+        ident: &Ident,
+        has_inner_type: bool,
+        is_field_method_generated: bool,
+        is_for_field_method_generated: bool,
+    ) -> Result<(), TokenStream> {
+        if has_inner_type && !is_field_method_generated && !is_for_field_method_generated {
+            Err(utils::error(
+                ident.span(),
+                "`inner_type` requires `field` or `for_field` on the same field",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
     fn parse_type(has_inner_type: bool, type_: Type) -> Result<Type, TokenStream> {
         if has_inner_type {
             let span = type_.span();
-            utils::first_generic_type(type_)
-                .ok_or_else(|| utils::error(span, "`inner_type` requires one generic type"))
+            utils::first_generic_type(type_).ok_or_else(|| {
+                utils::error(
+                    span,
+                    "`inner_type` requires a field type with a generic type (e.g. `PhantomData<T>`)",
+                )
+            })
         } else {
             Ok(type_)
         }
@@ -260,7 +444,28 @@ mod tests {
         let input = syn::parse_str::<DeriveInput>(
             "struct Test { #[updater(inner_type, field)] field: usize }",
         )?;
-        assert!(super::impl_block(&input).is_err());
+        let error = super::impl_block(&input).expect_err("no error").to_string();
+        assert!(error.contains("`inner_type` requires a field type with a generic type"));
+        Ok(())
+    }
+
+    #[test]
+    fn derive_with_on_update_without_apply() -> syn::Result<()> {
+        let input = syn::parse_str::<DeriveInput>(
+            "#[updater(on_update = refresh)] struct Test { #[updater(field)] field: usize }",
+        )?;
+        let error = super::impl_block(&input).expect_err("no error").to_string();
+        assert!(error.contains("`on_update` requires `apply` attribute"));
+        Ok(())
+    }
+
+    #[test]
+    fn derive_with_inner_type_without_generated_method() -> syn::Result<()> {
+        let input = syn::parse_str::<DeriveInput>(
+            "struct Test { #[updater(inner_type)] field: PhantomData<usize> }",
+        )?;
+        let error = super::impl_block(&input).expect_err("no error").to_string();
+        assert!(error.contains("`inner_type` requires `field` or `for_field` on the same field"));
         Ok(())
     }
 }
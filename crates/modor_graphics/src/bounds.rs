@@ -0,0 +1,62 @@
+use modor_physics::modor_math::Vec2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Bounds2D {
+    center: Vec2,
+    half_size: Vec2,
+}
+
+impl Bounds2D {
+    pub(crate) fn new(center: Vec2, size: Vec2, rotation: f32) -> Self {
+        let half_size = Vec2::new(size.x.abs(), size.y.abs()) / 2.;
+        let cos = rotation.cos().abs();
+        let sin = rotation.sin().abs();
+        Self {
+            center,
+            half_size: Vec2::new(
+                cos.mul_add(half_size.x, sin * half_size.y),
+                sin.mul_add(half_size.x, cos * half_size.y),
+            ),
+        }
+    }
+
+    pub(crate) fn intersects(self, other: Self) -> bool {
+        let distance = self.center - other.center;
+        let max_distance = self.half_size + other.half_size;
+        distance.x.abs() <= max_distance.x && distance.y.abs() <= max_distance.y
+    }
+}
+
+#[cfg(test)]
+mod bounds_tests {
+    use crate::bounds::Bounds2D;
+    use modor_physics::modor_math::Vec2;
+    use std::f32::consts::FRAC_PI_4;
+
+    #[test]
+    fn check_intersection_with_axis_aligned_bounds() {
+        let bounds = Bounds2D::new(Vec2::ZERO, Vec2::new(2., 1.), 0.);
+        let touching = Bounds2D::new(Vec2::new(1.5, 0.), Vec2::ONE, 0.);
+        let overlapping = Bounds2D::new(Vec2::new(1.4, 0.4), Vec2::ONE, 0.);
+        let outside = Bounds2D::new(Vec2::new(0., 1.1), Vec2::ONE, 0.);
+        assert!(bounds.intersects(touching));
+        assert!(bounds.intersects(overlapping));
+        assert!(!bounds.intersects(outside));
+    }
+
+    #[test]
+    fn check_intersection_with_rotated_bounds() {
+        let bounds = Bounds2D::new(Vec2::ZERO, Vec2::ONE, 0.);
+        let rotated = Bounds2D::new(Vec2::new(1.15, 0.), Vec2::ONE, FRAC_PI_4);
+        let not_rotated = Bounds2D::new(Vec2::new(1.15, 0.), Vec2::ONE, 0.);
+        assert!(bounds.intersects(rotated));
+        assert!(!bounds.intersects(not_rotated));
+    }
+
+    #[test]
+    fn check_intersection_with_negative_size() {
+        let bounds = Bounds2D::new(Vec2::ZERO, Vec2::new(-1., -1.), 0.);
+        let other = Bounds2D::new(Vec2::new(0.9, 0.), Vec2::ONE, 0.);
+        assert!(bounds.intersects(other));
+    }
+}
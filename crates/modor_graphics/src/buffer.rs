@@ -11,6 +11,10 @@ use wgpu::{
 pub(crate) struct Buffer<T> {
     inner: wgpu::Buffer,
     len: usize,
+    capacity: usize,
+    reserved_capacity: usize,
+    #[cfg(test)]
+    pub(crate) reallocation_count: usize,
     usages: BufferUsages,
     label: String,
     phantom: PhantomData<fn(T)>,
@@ -26,11 +30,25 @@ where
         usages: BufferUsages,
         label: impl Into<String>,
     ) -> Self {
-        let cast_data = Self::cast_data(data);
+        Self::with_capacity(gpu, data, 0, usages, label)
+    }
+
+    pub(crate) fn with_capacity(
+        gpu: &Gpu,
+        data: &[T],
+        capacity: usize,
+        usages: BufferUsages,
+        label: impl Into<String>,
+    ) -> Self {
         let label = label.into();
+        let capacity = capacity.max(data.len());
         Self {
-            inner: Self::create_buffer(gpu, cast_data, usages, &label),
+            inner: Self::create_buffer(gpu, data, capacity, usages, &label),
             len: data.len(),
+            capacity,
+            reserved_capacity: capacity,
+            #[cfg(test)]
+            reallocation_count: 0,
             usages,
             label,
             phantom: PhantomData,
@@ -49,20 +67,45 @@ where
         self.inner.slice(..)
     }
 
+    pub(crate) fn reserve(&mut self, capacity: usize) {
+        self.reserved_capacity = self.reserved_capacity.max(capacity);
+    }
+
     pub(crate) fn update(&mut self, gpu: &Gpu, data: &[T]) {
-        let cast_data = Self::cast_data(data);
-        if self.len < data.len() {
-            self.inner = Self::create_buffer(gpu, cast_data, self.usages, &self.label);
-            self.len = data.len();
+        if self.capacity < data.len() || self.capacity < self.reserved_capacity {
+            self.capacity = self.reserved_capacity.max(data.len());
+            self.inner = Self::create_buffer(gpu, data, self.capacity, self.usages, &self.label);
+            #[cfg(test)]
+            {
+                self.reallocation_count += 1;
+            }
         } else {
-            gpu.queue.write_buffer(&self.inner, 0, cast_data);
+            gpu.queue
+                .write_buffer(&self.inner, 0, Self::cast_data(data));
         }
+        self.len = data.len();
     }
 
-    fn create_buffer(gpu: &Gpu, data: &[u8], usages: BufferUsages, label: &str) -> wgpu::Buffer {
+    fn create_buffer(
+        gpu: &Gpu,
+        data: &[T],
+        capacity: usize,
+        usages: BufferUsages,
+        label: &str,
+    ) -> wgpu::Buffer {
+        let cast_data = Self::cast_data(data);
+        let capacity_size = capacity * size_of::<T>();
+        let mut padded_data;
+        let contents = if cast_data.len() < capacity_size {
+            padded_data = cast_data.to_vec();
+            padded_data.resize(capacity_size, 0);
+            &padded_data
+        } else {
+            cast_data
+        };
         gpu.device.create_buffer_init(&BufferInitDescriptor {
             label: Some(&format!("modor_buffer:{label}")),
-            contents: data,
+            contents,
             usage: usages,
         })
     }
@@ -119,3 +162,61 @@ impl BufferBindGroup {
         )
     }
 }
+
+#[cfg(test)]
+mod buffer_tests {
+    use crate::buffer::Buffer;
+    use crate::gpu::{Gpu, GpuManager};
+    use log::Level;
+    use modor::{App, State};
+    use std::sync::Arc;
+    use wgpu::BufferUsages;
+
+    #[modor::test(disabled(windows, macos, android, wasm))]
+    fn update_without_reserved_capacity() {
+        let gpu = gpu();
+        let mut buffer = Buffer::new(&gpu, &[0_u32; 10], usages(), "test");
+        update_many_times(&gpu, &mut buffer);
+        assert_eq!(buffer.len(), 100);
+        assert_eq!(buffer.reallocation_count, 5);
+    }
+
+    #[modor::test(disabled(windows, macos, android, wasm))]
+    fn update_with_capacity() {
+        let gpu = gpu();
+        let mut buffer = Buffer::with_capacity(&gpu, &[0_u32; 10], 100, usages(), "test");
+        update_many_times(&gpu, &mut buffer);
+        assert_eq!(buffer.len(), 100);
+        assert_eq!(buffer.reallocation_count, 0);
+    }
+
+    #[modor::test(disabled(windows, macos, android, wasm))]
+    fn update_with_reserved_capacity() {
+        let gpu = gpu();
+        let mut buffer = Buffer::new(&gpu, &[0_u32; 10], usages(), "test");
+        buffer.reserve(200);
+        update_many_times(&gpu, &mut buffer);
+        assert_eq!(buffer.len(), 100);
+        assert_eq!(buffer.reallocation_count, 1);
+    }
+
+    fn update_many_times(gpu: &Gpu, buffer: &mut Buffer<u32>) {
+        for len in (20..=100).step_by(20) {
+            buffer.update(gpu, &vec![0; len]);
+        }
+    }
+
+    fn gpu() -> Arc<Gpu> {
+        let mut app = App::new::<Root>(Level::Info);
+        app.get_mut::<GpuManager>().get_or_init().clone()
+    }
+
+    fn usages() -> BufferUsages {
+        BufferUsages::VERTEX | BufferUsages::COPY_DST
+    }
+
+    #[derive(Default)]
+    struct Root;
+
+    impl State for Root {}
+}
@@ -1,3 +1,4 @@
+use crate::bounds::Bounds2D;
 use crate::buffer::{Buffer, BufferBindGroup};
 use crate::gpu::{Gpu, GpuManager};
 use crate::{Size, Target};
@@ -102,6 +103,11 @@ impl Camera2D {
         glob.size = self.size;
         glob.rotation = self.rotation;
         glob.register_targets(&self.targets);
+        glob.visible_bounds = target_sizes
+            .iter()
+            .map(|(_, target_size)| glob.visible_size((*target_size).into()))
+            .reduce(|size1, size2| Vec2::new(size1.x.max(size2.x), size1.y.max(size2.y)))
+            .map(|size| Bounds2D::new(self.position, size, self.rotation));
         for (target_index, target_size) in target_sizes {
             let transform = self.gpu_transform(target_size.into());
             glob.update_target(&gpu, target_index, transform);
@@ -138,6 +144,7 @@ pub struct Camera2DGlob {
     pub(crate) size: Vec2,
     pub(crate) rotation: f32,
     pub(crate) targets: Vec<GlobRef<Target>>,
+    pub(crate) visible_bounds: Option<Bounds2D>,
     target_uniforms: FxHashMap<usize, CameraUniform>,
 }
 
@@ -148,6 +155,7 @@ impl Default for Camera2DGlob {
             size: Vec2::ONE,
             rotation: 0.,
             targets: vec![],
+            visible_bounds: None,
             target_uniforms: FxHashMap::default(),
         }
     }
@@ -191,13 +199,16 @@ impl Camera2DGlob {
     }
 
     fn world_transform(&self, target_size: Vec2) -> Mat4 {
-        let x_scale = 1.0_f32.min(target_size.y / target_size.x);
-        let y_scale = 1.0_f32.min(target_size.x / target_size.y);
-        let scale = self.size.with_scale(Vec2::new(1. / x_scale, 1. / y_scale));
-        Mat4::from_scale(scale.with_z(1.))
+        Mat4::from_scale(self.visible_size(target_size).with_z(1.))
             * Quat::from_z(-self.rotation).matrix()
             * Mat4::from_position(self.position.with_z(0.))
     }
+
+    fn visible_size(&self, target_size: Vec2) -> Vec2 {
+        let x_scale = 1.0_f32.min(target_size.y / target_size.x);
+        let y_scale = 1.0_f32.min(target_size.x / target_size.y);
+        self.size.with_scale(Vec2::new(1. / x_scale, 1. / y_scale))
+    }
 }
 
 #[derive(Debug)]
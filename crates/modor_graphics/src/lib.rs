@@ -13,6 +13,7 @@
 
 mod animation;
 mod anti_aliasing;
+mod bounds;
 mod buffer;
 mod camera;
 mod color;
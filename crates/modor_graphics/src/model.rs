@@ -1,3 +1,4 @@
+use crate::bounds::Bounds2D;
 use crate::buffer::Buffer;
 use crate::gpu::Gpu;
 use crate::material::InstanceDataType;
@@ -89,6 +90,23 @@ pub struct Model2D {
     /// The material used to render the model.
     #[builder(form(value))]
     pub material: GlobRef<Mat>,
+    /// The minimum number of instances for which the instance group of the model reserves memory.
+    ///
+    /// This is a hint used to avoid reallocating instance buffers when many models sharing
+    /// the same group are created.
+    ///
+    /// Default is `0`.
+    #[builder(form(value))]
+    pub group_capacity: usize,
+    /// Whether the model is culled when it is outside the visible area of its camera.
+    ///
+    /// Culling assumes the model is rendered in the rectangle defined by its position, size and
+    /// rotation. If the shader of the material moves the vertices outside this rectangle,
+    /// culling should be disabled to avoid hiding a visible model.
+    ///
+    /// Default is `true`.
+    #[builder(form(value))]
+    pub is_culling_enabled: bool,
     mesh: GlobRef<Mesh>,
     glob: Glob<Model2DGlob>,
     groups: StateHandle<InstanceGroups2D>,
@@ -106,6 +124,8 @@ impl Model2D {
             rotation: 0.,
             body: None,
             z_index: 0,
+            group_capacity: 0,
+            is_culling_enabled: true,
             glob: Glob::from_app(app),
             camera,
             material,
@@ -158,7 +178,8 @@ pub struct InstanceGroup2DProperties {
 }
 
 impl InstanceGroup2DProperties {
-    fn new(model: &Model2D) -> Self {
+    /// Returns the properties of the group in which the `model` is rendered.
+    pub fn new(model: &Model2D) -> Self {
         Self {
             mesh: model.mesh.index(),
             camera: model.camera.index(),
@@ -168,10 +189,14 @@ impl InstanceGroup2DProperties {
 }
 
 /// The information about instance groups managed by the graphics crate.
+///
+/// Before rendering, the instances of a group are culled: only the models whose bounds intersect
+/// the visible area of the group camera are sent to the GPU.
 #[derive(FromApp)]
 pub struct InstanceGroups2D {
     pub(crate) groups: FxHashMap<InstanceGroup2DProperties, InstanceGroup2D>,
     model_groups: Vec<Option<InstanceGroup2DProperties>>,
+    cameras: StateHandle<Globals<Camera2DGlob>>,
 }
 
 impl State for InstanceGroups2D {
@@ -192,15 +217,47 @@ impl InstanceGroups2D {
         self.groups.keys().copied()
     }
 
-    pub(crate) fn sync(&mut self, gpu: &Gpu) {
-        for group in self.groups.values_mut() {
-            group.sync(gpu);
+    /// Returns the number of instances of a `group` sent to the GPU during the last rendering.
+    ///
+    /// Culled instances are not counted.
+    pub fn visible_instance_count(&self, group: InstanceGroup2DProperties) -> usize {
+        self.groups
+            .get(&group)
+            .map_or(0, |group| group.visible_positions.len())
+    }
+
+    /// Reserves capacity for at least `additional` more instances in a `group`.
+    ///
+    /// GPU buffers of the group are then allocated for the reserved instances, so that they are
+    /// not reallocated while the group grows.
+    pub fn reserve(&mut self, group: InstanceGroup2DProperties, additional: usize) {
+        self.group_mut(group).reserve(additional);
+    }
+
+    pub(crate) fn sync(app: &mut App, gpu: &Gpu) {
+        let handle = app.handle::<Self>();
+        let groups = handle.get(app);
+        let cameras = groups.cameras.get(app);
+        let visible_bounds: FxHashMap<_, _> = groups
+            .groups
+            .keys()
+            .map(|group| {
+                let bounds = cameras
+                    .get(group.camera)
+                    .and_then(|camera| camera.visible_bounds);
+                (group.camera, bounds)
+            })
+            .collect();
+        for (properties, group) in &mut handle.get_mut(app).groups {
+            group.sync(gpu, visible_bounds[&properties.camera]);
         }
     }
 
     fn register_model(&mut self, model: &Model2D, data: Vec<u8>, data_type: InstanceDataType) {
         let group = InstanceGroup2DProperties::new(model);
-        self.group_mut(group).register_model(model, data, data_type);
+        let group_mut = self.group_mut(group);
+        group_mut.reserve_total(model.group_capacity);
+        group_mut.register_model(model, data, data_type);
         let model_index = model.glob.index();
         (self.model_groups.len()..=model_index).for_each(|_| self.model_groups.push(None));
         self.model_groups[model_index] = Some(group);
@@ -211,6 +268,7 @@ impl InstanceGroups2D {
         let old_group =
             self.model_groups[model_index].expect("internal error: missing model groups");
         let group = InstanceGroup2DProperties::new(model);
+        self.group_mut(group).reserve_total(model.group_capacity);
         if group == old_group {
             self.group_mut(group).update_model(model, data, data_type);
         } else {
@@ -230,8 +288,13 @@ pub(crate) struct InstanceGroup2D {
     pub(crate) buffers: FxHashMap<TypeId, InstanceGroupBuffer>,
     pub(crate) model_indexes: Vec<usize>,
     pub(crate) z_indexes: Vec<f32>,
+    pub(crate) visible_positions: Vec<usize>,
     model_positions: FxHashMap<usize, usize>,
+    model_bounds: Vec<Option<Bounds2D>>,
+    visible_bounds: Option<Bounds2D>,
+    is_culling_outdated: bool,
     secondary_type: Option<TypeId>,
+    capacity: usize,
 }
 
 impl InstanceGroup2D {
@@ -249,6 +312,8 @@ impl InstanceGroup2D {
         self.model_positions
             .insert(model_index, self.model_indexes.len());
         self.model_indexes.push(model_index);
+        self.model_bounds.push(Self::bounds(model));
+        self.is_culling_outdated = true;
         let instance = Instance::new(model);
         self.z_indexes.push(instance.z());
         self.buffer_mut(TypeId::of::<Instance>(), size_of::<Instance>())
@@ -262,6 +327,11 @@ impl InstanceGroup2D {
 
     fn update_model(&mut self, model: &Model2D, data: Vec<u8>, data_type: InstanceDataType) {
         let position = self.model_positions[&model.glob().index()];
+        let bounds = Self::bounds(model);
+        if self.model_bounds[position] != bounds {
+            self.model_bounds[position] = bounds;
+            self.is_culling_outdated = true;
+        }
         let instance = Instance::new(model);
         self.z_indexes[position] = instance.z();
         self.buffer_mut(TypeId::of::<Instance>(), size_of::<Instance>())
@@ -272,6 +342,20 @@ impl InstanceGroup2D {
         }
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.reserve_total(self.model_indexes.len() + additional);
+    }
+
+    fn reserve_total(&mut self, capacity: usize) {
+        if capacity > self.capacity {
+            self.capacity = capacity;
+            self.buffer_mut(TypeId::of::<Instance>(), size_of::<Instance>());
+            for buffer in self.buffers.values_mut() {
+                buffer.reserve(capacity);
+            }
+        }
+    }
+
     fn delete_model(&mut self, model_index: usize) {
         let position = self
             .model_positions
@@ -279,6 +363,8 @@ impl InstanceGroup2D {
             .expect("internal error: missing model");
         self.model_indexes.swap_remove(position);
         self.z_indexes.swap_remove(position);
+        self.model_bounds.swap_remove(position);
+        self.is_culling_outdated = true;
         if let Some(&moved_model_index) = self.model_indexes.get(position) {
             self.model_positions.insert(moved_model_index, position);
         }
@@ -287,16 +373,44 @@ impl InstanceGroup2D {
         }
     }
 
-    fn sync(&mut self, gpu: &Gpu) {
+    fn sync(&mut self, gpu: &Gpu, visible_bounds: Option<Bounds2D>) {
+        if self.is_culling_outdated || self.visible_bounds != visible_bounds {
+            let visible_positions: Vec<_> = (0..self.model_bounds.len())
+                .filter(|&position| {
+                    visible_bounds
+                        .zip(self.model_bounds[position])
+                        .map_or(true, |(bounds, model_bounds)| {
+                            bounds.intersects(model_bounds)
+                        })
+                })
+                .collect();
+            if visible_positions != self.visible_positions {
+                self.visible_positions = visible_positions;
+                for buffer in self.buffers.values_mut() {
+                    buffer.is_updated = true;
+                }
+            }
+            self.visible_bounds = visible_bounds;
+            self.is_culling_outdated = false;
+        }
         for buffer in self.buffers.values_mut() {
-            buffer.sync(gpu);
+            buffer.sync(gpu, &self.visible_positions, self.capacity);
         }
     }
 
+    fn bounds(model: &Model2D) -> Option<Bounds2D> {
+        model
+            .is_culling_enabled
+            .then(|| Bounds2D::new(model.position, model.size, model.rotation))
+    }
+
     fn buffer_mut(&mut self, type_id: TypeId, type_size: usize) -> &mut InstanceGroupBuffer {
-        self.buffers
-            .entry(type_id)
-            .or_insert_with(|| InstanceGroupBuffer::new(type_size))
+        let capacity = self.capacity;
+        self.buffers.entry(type_id).or_insert_with(|| {
+            let mut buffer = InstanceGroupBuffer::new(type_size);
+            buffer.reserve(capacity);
+            buffer
+        })
     }
 }
 
@@ -304,6 +418,7 @@ impl InstanceGroup2D {
 pub(crate) struct InstanceGroupBuffer {
     pub(crate) buffer: Option<Buffer<u8>>,
     pub(crate) data: Vec<u8>,
+    visible_data: Vec<u8>,
     item_size: usize,
     is_updated: bool,
 }
@@ -313,11 +428,19 @@ impl InstanceGroupBuffer {
         Self {
             buffer: None,
             data: vec![],
+            visible_data: vec![],
             item_size,
             is_updated: false,
         }
     }
 
+    fn reserve(&mut self, capacity: usize) {
+        let capacity = capacity * self.item_size;
+        self.data.reserve(capacity.saturating_sub(self.data.len()));
+        self.visible_data
+            .reserve(capacity.saturating_sub(self.visible_data.len()));
+    }
+
     fn push(&mut self, item: &[u8]) {
         self.data.extend(item);
         self.is_updated = true;
@@ -337,20 +460,29 @@ impl InstanceGroupBuffer {
         for i in (0..self.item_size).rev() {
             self.data.swap_remove(position * self.item_size + i);
         }
+        self.is_updated = true;
     }
 
-    fn sync(&mut self, gpu: &Gpu) {
+    fn sync(&mut self, gpu: &Gpu, visible_positions: &[usize], capacity: usize) {
         if self.is_updated {
-            self.buffer
-                .get_or_insert_with(|| {
-                    Buffer::new(
-                        gpu,
-                        &self.data,
-                        BufferUsages::VERTEX | BufferUsages::COPY_DST,
-                        "instance_group",
-                    )
-                })
-                .update(gpu, &self.data);
+            self.visible_data.clear();
+            for &position in visible_positions {
+                let range = (position * self.item_size)..((position + 1) * self.item_size);
+                self.visible_data.extend_from_slice(&self.data[range]);
+            }
+            let capacity = capacity * self.item_size;
+            if let Some(buffer) = &mut self.buffer {
+                buffer.reserve(capacity);
+                buffer.update(gpu, &self.visible_data);
+            } else {
+                self.buffer = Some(Buffer::with_capacity(
+                    gpu,
+                    &self.visible_data,
+                    capacity,
+                    BufferUsages::VERTEX | BufferUsages::COPY_DST,
+                    "instance_group",
+                ));
+            }
             self.is_updated = false;
         }
     }
@@ -387,3 +519,103 @@ impl<const L: u32> VertexBuffer<L> for Instance {
     ];
     const STEP_MODE: VertexStepMode = VertexStepMode::Instance;
 }
+
+#[cfg(test)]
+mod model_tests {
+    use crate::{
+        InstanceGroup2DProperties, InstanceGroups2D, Model2D, Size, Texture, TextureSource,
+        TextureUpdater,
+    };
+    use log::Level;
+    use modor::{App, FromApp, Glob, State};
+    use modor_resources::testing::wait_resources;
+    use modor_resources::{Res, ResUpdater};
+
+    #[modor::test(disabled(windows, macos, android, wasm))]
+    fn add_models_without_reserved_capacity() {
+        let mut app = configure_app();
+        add_models(&mut app, 100, 0);
+        assert!(reallocation_count(&mut app) > 1);
+    }
+
+    #[modor::test(disabled(windows, macos, android, wasm))]
+    fn add_models_with_capacity_reserved_before_rendering() {
+        let mut app = configure_app();
+        let model = Model2D::new(&mut app);
+        let group = InstanceGroup2DProperties::new(&model);
+        app.get_mut::<Root>().models.push(model);
+        app.get_mut::<InstanceGroups2D>().reserve(group, 99);
+        add_models(&mut app, 99, 0);
+        assert_eq!(reallocation_count(&mut app), 0);
+    }
+
+    #[modor::test(disabled(windows, macos, android, wasm))]
+    fn add_models_with_capacity_reserved_after_rendering() {
+        let mut app = configure_app();
+        add_models(&mut app, 10, 0);
+        let group = InstanceGroup2DProperties::new(&app.get_mut::<Root>().models[0]);
+        app.get_mut::<InstanceGroups2D>().reserve(group, 90);
+        add_models(&mut app, 90, 0);
+        assert_eq!(reallocation_count(&mut app), 1);
+    }
+
+    #[modor::test(disabled(windows, macos, android, wasm))]
+    fn add_models_with_group_capacity() {
+        let mut app = configure_app();
+        add_models(&mut app, 100, 100);
+        assert_eq!(reallocation_count(&mut app), 1);
+    }
+
+    fn configure_app() -> App {
+        let mut app = App::new::<Root>(Level::Info);
+        wait_resources(&mut app);
+        app
+    }
+
+    fn add_models(app: &mut App, count: usize, group_capacity: usize) {
+        for index in 0..count {
+            let model = Model2D::new(app).with_group_capacity(group_capacity);
+            app.get_mut::<Root>().models.push(model);
+            if index % 10 == 9 || index == count - 1 {
+                app.update();
+            }
+        }
+    }
+
+    fn reallocation_count(app: &mut App) -> usize {
+        let group = InstanceGroup2DProperties::new(&app.get_mut::<Root>().models[0]);
+        app.get_mut::<InstanceGroups2D>().groups[&group]
+            .primary_buffer()
+            .expect("buffer not created")
+            .reallocation_count
+    }
+
+    struct Root {
+        target: Glob<Res<Texture>>,
+        models: Vec<Model2D>,
+    }
+
+    impl FromApp for Root {
+        fn from_app(app: &mut App) -> Self {
+            Self {
+                target: Glob::from_app(app),
+                models: vec![],
+            }
+        }
+    }
+
+    impl State for Root {
+        fn init(&mut self, app: &mut App) {
+            TextureUpdater::default()
+                .res(ResUpdater::default().source(TextureSource::Size(Size::new(30, 20))))
+                .is_target_enabled(true)
+                .apply(app, &self.target);
+        }
+
+        fn update(&mut self, app: &mut App) {
+            for model in &mut self.models {
+                model.update(app);
+            }
+        }
+    }
+}
@@ -120,7 +120,7 @@ impl Target {
 
     pub(crate) fn render(&mut self, app: &mut App, gpu: &Gpu, view: TextureView) {
         app.take(MaterialManager::update_material_bind_groups);
-        app.get_mut::<InstanceGroups2D>().sync(gpu);
+        InstanceGroups2D::sync(app, gpu);
         self.update_loaded(gpu);
         let anti_aliasing = self.fixed_anti_aliasing();
         let loaded = self
@@ -265,11 +265,12 @@ impl Target {
         let mut sorted_instances: Vec<_> = self
             .group_iter(app, groups, true)
             .flat_map(|group| {
-                groups.groups[&group]
-                    .z_indexes
-                    .iter()
-                    .enumerate()
-                    .map(move |(instance_index, z)| (group, instance_index, z))
+                let instances = &groups.groups[&group];
+                instances.visible_positions.iter().enumerate().map(
+                    move |(instance_index, &position)| {
+                        (group, instance_index, instances.z_indexes[position])
+                    },
+                )
             })
             .collect();
         sorted_instances.sort_unstable_by(|(group1, _, z1), (group2, _, z2)| {
@@ -346,7 +347,7 @@ impl Target {
             if let Some(index) = instance_index {
                 index as u32..index as u32 + 1
             } else {
-                0..group.model_indexes.len() as u32
+                0..group.visible_positions.len() as u32
             },
         );
         Some(())
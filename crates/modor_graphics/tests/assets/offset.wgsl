@@ -0,0 +1,48 @@
+struct Camera {
+    transform: mat4x4<f32>,
+};
+
+struct Vertex {
+    @location(0)
+    position: vec3<f32>,
+    @location(1)
+    texture_position: vec2<f32>,
+};
+
+struct Instance {
+    @location(2)
+    transform_0: vec4<f32>,
+    @location(3)
+    transform_1: vec4<f32>,
+    @location(4)
+    transform_2: vec4<f32>,
+    @location(5)
+    transform_3: vec4<f32>,
+};
+
+struct Fragment {
+    @builtin(position)
+    position: vec4<f32>,
+};
+
+@group(0)
+@binding(0)
+var<uniform> camera: Camera;
+
+@vertex
+fn vs_main(vertex: Vertex, instance: Instance) -> Fragment {
+    let transform = mat4x4<f32>(
+        instance.transform_0,
+        instance.transform_1,
+        instance.transform_2,
+        instance.transform_3 - vec4<f32>(10., 0., 0., 0.),
+    );
+    return Fragment(
+        camera.transform * transform * vec4<f32>(vertex.position, 1.),
+    );
+}
+
+@fragment
+fn fs_main(fragment: Fragment) -> @location(0) vec4<f32> {
+    return vec4(1., 0., 0., 1.);
+}
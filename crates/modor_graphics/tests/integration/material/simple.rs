@@ -3,8 +3,8 @@ use log::Level;
 use modor::{App, FromApp, Glob, GlobRef, State};
 use modor_graphics::testing::{assert_max_component_diff, assert_same};
 use modor_graphics::{
-    Color, MatGlob, MatUpdater, Material, Model2D, Model2DGlob, ShaderGlob, ShaderUpdater, Size,
-    Texture, TextureSource, TextureUpdater,
+    Color, InstanceGroup2DProperties, InstanceGroups2D, MatGlob, MatUpdater, Material, Model2D,
+    Model2DGlob, ShaderGlob, ShaderUpdater, Size, Texture, TextureSource, TextureUpdater,
 };
 use modor_input::modor_math::Vec2;
 use modor_resources::testing::wait_resources;
@@ -81,6 +81,46 @@ fn set_shader() {
     assert_same(&app, &target, "material#red");
 }
 
+#[modor::test(disabled(windows, macos, android, wasm))]
+fn set_shader_moving_vertices_with_culling_disabled() {
+    let (mut app, target) = configure_app();
+    let shader = root(&mut app).offset_shader.to_ref();
+    app.take::<Root, _>(|root, app| {
+        MatUpdater::default()
+            .shader(shader)
+            .apply(app, &root.material);
+        root.model.position = Vec2::new(10., 0.);
+        root.model.is_culling_enabled = false;
+    });
+    app.update();
+    app.update();
+    assert_same(&app, &target, "material#red");
+    let group = InstanceGroup2DProperties::new(&root(&mut app).model);
+    let count = app
+        .get_mut::<InstanceGroups2D>()
+        .visible_instance_count(group);
+    assert_eq!(count, 1);
+}
+
+#[modor::test(disabled(windows, macos, android, wasm))]
+fn set_shader_moving_vertices_with_culling_enabled() {
+    let (mut app, _target) = configure_app();
+    let shader = root(&mut app).offset_shader.to_ref();
+    app.take::<Root, _>(|root, app| {
+        MatUpdater::default()
+            .shader(shader)
+            .apply(app, &root.material);
+        root.model.position = Vec2::new(10., 0.);
+    });
+    app.update();
+    app.update();
+    let group = InstanceGroup2DProperties::new(&root(&mut app).model);
+    let count = app
+        .get_mut::<InstanceGroups2D>()
+        .visible_instance_count(group);
+    assert_eq!(count, 0);
+}
+
 fn configure_app() -> (App, GlobRef<Res<Texture>>) {
     let mut app = App::new::<Root>(Level::Info);
     wait_resources(&mut app);
@@ -98,6 +138,7 @@ struct Root {
     texture: Glob<Res<Texture>>,
     shader: ShaderGlob<TestMaterial>,
     red_shader: ShaderGlob<TestMaterial>,
+    offset_shader: ShaderGlob<TestMaterial>,
     material: MatGlob<TestMaterial>,
     model: Model2D,
     target: Glob<Res<Texture>>,
@@ -109,12 +150,14 @@ impl FromApp for Root {
         let texture = Glob::from_app(app);
         let shader = ShaderGlob::from_app(app);
         let red_shader = ShaderGlob::from_app(app);
+        let offset_shader = ShaderGlob::from_app(app);
         let material = MatGlob::from_app(app);
         let model = Model2D::new(app).with_material(material.to_ref());
         Self {
             texture,
             shader,
             red_shader,
+            offset_shader,
             material,
             model,
             target,
@@ -134,6 +177,9 @@ impl State for Root {
         ShaderUpdater::default()
             .res(ResUpdater::default().path("../tests/assets/red.wgsl"))
             .apply(app, &self.red_shader);
+        ShaderUpdater::default()
+            .res(ResUpdater::default().path("../tests/assets/offset.wgsl"))
+            .apply(app, &self.offset_shader);
         MatUpdater::default()
             .shader(self.shader.to_ref())
             .textures(vec![self.texture.to_ref()])
@@ -2,8 +2,8 @@ use log::Level;
 use modor::{App, FromApp, Glob, GlobRef, State};
 use modor_graphics::testing::{assert_max_component_diff, assert_same};
 use modor_graphics::{
-    Camera2DGlob, Color, DefaultMaterial2D, DefaultMaterial2DUpdater, MatGlob, Model2D, Size,
-    Texture, TextureSource, TextureUpdater,
+    Camera2DGlob, Color, DefaultMaterial2D, DefaultMaterial2DUpdater, InstanceGroup2DProperties,
+    InstanceGroups2D, MatGlob, Model2D, Size, Texture, TextureSource, TextureUpdater,
 };
 use modor_input::modor_math::Vec2;
 use modor_physics::{Body2D, Body2DUpdater};
@@ -135,6 +135,96 @@ fn set_material() {
     assert_same(&app, &target, "model#other_material");
 }
 
+#[modor::test(disabled(windows, macos, android, wasm))]
+fn reserve_capacity() {
+    let (mut app, target) = configure_app();
+    let group = InstanceGroup2DProperties::new(&root(&mut app).models[0]);
+    app.get_mut::<InstanceGroups2D>().reserve(group, 1000);
+    app.update();
+    assert_same(&app, &target, "model#default");
+}
+
+#[modor::test(disabled(windows, macos, android, wasm))]
+fn set_group_capacity() {
+    let (mut app, target) = configure_app();
+    root(&mut app).models[0].group_capacity = 1000;
+    app.update();
+    app.update();
+    assert_same(&app, &target, "model#default");
+}
+
+#[modor::test(disabled(windows, macos, android, wasm))]
+fn cull_models() {
+    let (mut app, target) = configure_app();
+    let camera = camera1(&mut app);
+    let material = root(&mut app).material1.to_ref();
+    let partially_visible_model = Model2D::new(&mut app)
+        .with_position(Vec2::new(1.1, 0.))
+        .with_camera(camera.clone())
+        .with_material(material.clone());
+    let rotated_model = Model2D::new(&mut app)
+        .with_position(Vec2::new(-1.3, 0.))
+        .with_rotation(FRAC_PI_4)
+        .with_camera(camera.clone())
+        .with_material(material.clone());
+    let hidden_model = Model2D::new(&mut app)
+        .with_position(Vec2::new(0., 1.3))
+        .with_camera(camera)
+        .with_material(material);
+    root(&mut app).models.push(partially_visible_model);
+    root(&mut app).models.push(rotated_model);
+    root(&mut app).models.push(hidden_model);
+    app.update();
+    app.update();
+    assert_same(&app, &target, "model#culled");
+    assert_eq!(visible_instance_count(&mut app), 3);
+}
+
+#[modor::test(disabled(windows, macos, android, wasm))]
+fn disable_model_culling() {
+    let (mut app, target) = configure_app();
+    let camera = camera1(&mut app);
+    let material = root(&mut app).material1.to_ref();
+    let hidden_model = Model2D::new(&mut app)
+        .with_position(Vec2::new(0., 1.3))
+        .with_camera(camera)
+        .with_material(material)
+        .with_is_culling_enabled(false);
+    root(&mut app).models.push(hidden_model);
+    app.update();
+    app.update();
+    assert_same(&app, &target, "model#default");
+    assert_eq!(visible_instance_count(&mut app), 2);
+}
+
+#[modor::test(disabled(windows, macos, android, wasm))]
+fn cull_model_outside_camera() {
+    let (mut app, target) = configure_app();
+    root(&mut app).models[0].position = Vec2::new(10., 10.);
+    app.update();
+    app.update();
+    assert_same(&app, &target, "model#empty");
+    assert_eq!(visible_instance_count(&mut app), 0);
+}
+
+#[modor::test(disabled(windows, macos, android, wasm))]
+fn move_camera_to_culled_model() {
+    let (mut app, target) = configure_app();
+    root(&mut app).models[0].position = Vec2::new(10., 10.);
+    app.update();
+    app.update();
+    assert_same(&app, &target, "model#empty");
+    assert_eq!(visible_instance_count(&mut app), 0);
+    let target_glob = root(&mut app).target1.to_ref();
+    TextureUpdater::default()
+        .camera_position(Vec2::new(10., 10.))
+        .apply(&mut app, &target_glob);
+    app.update();
+    app.update();
+    assert_same(&app, &target, "model#default");
+    assert_eq!(visible_instance_count(&mut app), 1);
+}
+
 fn configure_app() -> (App, GlobRef<Res<Texture>>) {
     let mut app = App::new::<Root>(Level::Info);
     wait_resources(&mut app);
@@ -150,6 +240,12 @@ fn camera2(app: &mut App) -> GlobRef<Camera2DGlob> {
     root(app).target2.to_ref().get(app).camera().glob().to_ref()
 }
 
+fn visible_instance_count(app: &mut App) -> usize {
+    let group = InstanceGroup2DProperties::new(&root(app).models[0]);
+    app.get_mut::<InstanceGroups2D>()
+        .visible_instance_count(group)
+}
+
 fn root(app: &mut App) -> &mut Root {
     app.get_mut::<Root>()
 }
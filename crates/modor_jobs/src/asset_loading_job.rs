@@ -1,8 +1,14 @@
-use crate::{platform, Job, VariableSend};
+use crate::{
+    platform, CancellationToken, HttpMethod, HttpRequest, Job, JobError, JobFuture, VariableSend,
+};
+use futures::channel::oneshot;
+use futures::channel::oneshot::Sender;
+use futures::future::Either;
+use futures::{future, pin_mut};
+use instant::Instant;
 use std::any::Any;
-use std::error::Error;
-use std::fmt::{Debug, Display, Formatter};
 use std::future::Future;
+use std::time::Duration;
 
 /// Name of the asset folder taken into account in the folder `CARGO_MANIFEST_DIR`.
 pub const ASSET_FOLDER_NAME: &str = "assets";
@@ -51,7 +57,10 @@ pub const ASSET_FOLDER_NAME: &str = "assets";
 #[derive(Debug)]
 pub struct AssetLoadingJob<T> {
     /// Actual job instance that can be used to retrieve the job result.
-    inner: Job<Result<T, AssetLoadingError>>,
+    inner: Job<Result<T, JobError>>,
+    timeout_sender: Option<Sender<Duration>>,
+    deadline: Option<Instant>,
+    is_timed_out: bool,
 }
 
 impl<T> AssetLoadingJob<T>
@@ -76,14 +85,72 @@ where
         F: Future<Output = T> + VariableSend,
     {
         let asset_path = path.as_ref().to_string();
-        Self {
-            inner: Job::<Result<T, AssetLoadingError>>::new(async move {
-                match platform::load_asset(asset_path).await {
-                    Ok(b) => Ok(f(b).await),
-                    Err(e) => Err(e),
+        Self::from_future(async move {
+            match platform::load_asset(asset_path).await {
+                Ok(b) => Ok(f(b).await),
+                Err(e) => Err(e),
+            }
+        })
+    }
+
+    /// Creates a new job to retrieve an asset from the first source of `sources` that succeeds,
+    /// and apply `f` on the bytes of the file.
+    ///
+    /// The sources are tried in order, and a source is tried only if all previous ones have
+    /// failed.
+    ///
+    /// If all sources fail, [`AssetLoadingJob::try_poll`] returns
+    /// [`JobError::AllSourcesFailed`] with the error of each source.
+    ///
+    /// See [`AssetLoadingJob::new`] for platform-specific details about
+    /// [`AssetSource::Path`] sources.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use modor_jobs::*;
+    /// #
+    /// fn load_texture() -> AssetLoadingJob<Vec<u8>> {
+    ///     AssetLoadingJob::from_sources(
+    ///         vec![
+    ///             AssetSource::Url("https://cdn.example.com/texture.png".into()),
+    ///             AssetSource::Path("texture.png".into()),
+    ///         ],
+    ///         |bytes| async { bytes },
+    ///     )
+    /// }
+    /// ```
+    pub fn from_sources<F>(
+        sources: Vec<AssetSource>,
+        f: impl FnOnce(Vec<u8>) -> F + VariableSend + Any,
+    ) -> Self
+    where
+        F: Future<Output = T> + VariableSend,
+    {
+        Self::from_future(async move {
+            let mut errors = vec![];
+            for source in sources {
+                match source.load().await {
+                    Ok(b) => return Ok(f(b).await),
+                    Err(e) => errors.push(e),
                 }
-            }),
+            }
+            Err(JobError::AllSourcesFailed(errors))
+        })
+    }
+
+    /// Returns the job with a `timeout`.
+    ///
+    /// If the job has not finished when the `timeout` is reached, the loading is stopped at its
+    /// next `.await` point and [`AssetLoadingJob::try_poll`] returns [`JobError::TimedOut`].
+    ///
+    /// The `timeout` starts when this method is called.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.deadline = Some(Instant::now() + timeout);
+        if let Some(sender) = self.timeout_sender.take() {
+            let _ = sender.send(timeout);
         }
+        self
     }
 
     /// Try polling the job result.
@@ -92,46 +159,103 @@ where
     ///
     /// # Errors
     ///
-    /// An error is returned if the asset has not been successfully loaded.
-    pub fn try_poll(&mut self) -> Result<Option<T>, AssetLoadingError> {
-        self.inner
-            .try_poll()
-            .expect("internal error: asset loading job has failed")
-            .map_or(Ok(None), |result| result.map(|r| Some(r)))
+    /// An error is returned if the asset has not been successfully loaded, if the job has been
+    /// cancelled, if the job has timed out or if `f` has panicked.
+    ///
+    /// In case the asset doesn't exist, [`JobError::NotFound`] is returned.
+    pub fn try_poll(&mut self) -> Result<Option<T>, JobError> {
+        if self.is_timed_out {
+            return Ok(None);
+        }
+        match self.inner.try_poll() {
+            Ok(None)
+                if self
+                    .deadline
+                    .is_some_and(|deadline| Instant::now() >= deadline) =>
+            {
+                self.inner.cancel();
+                self.is_timed_out = true;
+                Err(JobError::TimedOut)
+            }
+            Ok(None) => Ok(None),
+            Ok(Some(result)) => {
+                self.deadline = None;
+                result.map(Some)
+            }
+            Err(err) => {
+                self.deadline = None;
+                Err(err)
+            }
+        }
+    }
+
+    /// Cancels the job.
+    ///
+    /// The loading is stopped at its next `.await` point, and [`AssetLoadingJob::try_poll`]
+    /// then returns [`JobError::Cancelled`].
+    ///
+    /// This method has no effect if the job has already finished.
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    /// Returns a token that can be used to cancel the job from another place.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.inner.cancellation_token()
+    }
+
+    fn from_future(future: impl JobFuture<Result<T, JobError>>) -> Self {
+        let (timeout_sender, timeout_receiver) = oneshot::channel();
+        Self {
+            inner: Job::<Result<T, JobError>>::new(async move {
+                let timeout = async move {
+                    match timeout_receiver.await {
+                        Ok(timeout) => platform::sleep(timeout).await,
+                        Err(_) => future::pending().await,
+                    }
+                };
+                pin_mut!(future, timeout);
+                match future::select(future, timeout).await {
+                    Either::Left((result, _)) => result,
+                    Either::Right(((), _)) => Err(JobError::TimedOut),
+                }
+            }),
+            timeout_sender: Some(timeout_sender),
+            deadline: None,
+            is_timed_out: false,
+        }
     }
 }
 
-/// An error occurring during an asset loading job.
-#[derive(Debug, Clone, PartialEq, Eq)]
-#[non_exhaustive]
-pub enum AssetLoadingError {
-    /// The provided asset path contains unsupported characters.
-    InvalidAssetPath,
-    /// DOM `Window` object has not been found, can only occurs for web platform.
-    NotFoundDomWindow,
-    /// `location.href` property cannot be retrieved, can only occurs for web platform.
-    InvalidLocationHref(String),
-    /// I/O error occurred while retrieving the resource.
-    IoError(String),
-    /// App has not been correctly initialized (e.g. [`modor::main`] is not used).
-    InvalidAppInit,
+/// A source of an asset loaded by an [`AssetLoadingJob`].
+///
+/// # Examples
+///
+/// See [`AssetLoadingJob::from_sources`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AssetSource {
+    /// Path of the asset relative to the asset folder.
+    Path(String),
+    /// URL of the asset retrieved with an HTTP `GET` request.
+    Url(String),
 }
 
-// coverage: off (not necessary to test Display impl)
-impl Display for AssetLoadingError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl AssetSource {
+    async fn load(self) -> Result<Vec<u8>, JobError> {
         match self {
-            Self::InvalidAssetPath => write!(f, "invalid asset path"),
-            Self::NotFoundDomWindow => write!(f, "DOM window not found"),
-            Self::InvalidLocationHref(m) => write!(f, "invalid location.ref property: {m}"),
-            Self::IoError(m) => write!(f, "IO error: {m}"),
-            Self::InvalidAppInit => write!(
-                f,
-                "App incorrectly initialized (maybe modor::modor_main has not been used ?)"
-            ),
+            Self::Path(path) => platform::load_asset(path).await,
+            Self::Url(url) => {
+                let request = HttpRequest::new(HttpMethod::Get, &url);
+                match platform::send_http_request(request).await {
+                    Ok(response) if (200..300).contains(&response.status) => Ok(response.body),
+                    Ok(response) if response.status == 404 => Err(JobError::NotFound(url)),
+                    Ok(response) => Err(JobError::Io(format!(
+                        "unexpected HTTP status {} for `{url}`",
+                        response.status
+                    ))),
+                    Err(err) => Err(err),
+                }
+            }
         }
     }
 }
-// coverage: on
-
-impl Error for AssetLoadingError {}
@@ -0,0 +1,80 @@
+use crate::{platform, CancellationToken, Job, JobError};
+use std::path::PathBuf;
+
+/// An asynchronous job to write a file.
+///
+/// # Example
+///
+/// ```rust
+/// # use modor::*;
+/// # use modor_jobs::*;
+/// #
+/// struct LevelSaver {
+///     job: Option<FileWriteJob>,
+/// }
+///
+/// impl LevelSaver {
+///     fn save(&mut self, level: &str) {
+///         self.job = Some(FileWriteJob::new("level.txt", level.as_bytes().to_vec()));
+///     }
+///
+///     fn poll(&mut self) {
+///         if let Some(job) = &mut self.job {
+///             match job.try_poll() {
+///                 Ok(Some(())) => println!("Level saved"),
+///                 Ok(None) => return,
+///                 Err(err) => println!("Level not saved: {err}"),
+///             }
+///             self.job = None;
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct FileWriteJob {
+    inner: Job<Result<(), JobError>>,
+}
+
+impl FileWriteJob {
+    /// Creates a new job to write `bytes` in a file located at `path`.
+    ///
+    /// If the file already exists, it is overwritten.
+    ///
+    /// # Platform-specific
+    ///
+    /// - Web: file writing is not supported, so the job always fails with
+    ///   [`JobError::UnsupportedPlatform`].
+    /// - Other: the file is written at `path`, relative to the current working directory if
+    ///   `path` is relative.
+    pub fn new(path: impl Into<PathBuf>, bytes: Vec<u8>) -> Self {
+        let path = path.into();
+        Self {
+            inner: Job::new(platform::write_file(path, bytes)),
+        }
+    }
+
+    /// Try polling the job result.
+    ///
+    /// `None` is returned if the file is not yet written or if the result has already been
+    /// retrieved.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the file has not been successfully written or if the job has been
+    /// cancelled.
+    pub fn try_poll(&mut self) -> Result<Option<()>, JobError> {
+        self.inner.try_poll()?.transpose()
+    }
+
+    /// Cancels the job.
+    ///
+    /// This method has no effect if the job has already finished.
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    /// Returns a token that can be used to cancel the job from another place.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.inner.cancellation_token()
+    }
+}
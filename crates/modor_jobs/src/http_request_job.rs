@@ -0,0 +1,158 @@
+use crate::{platform, CancellationToken, Job, JobError};
+
+/// An asynchronous job to send an HTTP request.
+///
+/// # Example
+///
+/// ```rust
+/// # use modor::*;
+/// # use modor_jobs::*;
+/// #
+/// struct Leaderboard {
+///     job: Option<HttpRequestJob>,
+///     scores: Vec<u8>,
+/// }
+///
+/// impl Leaderboard {
+///     fn refresh(&mut self) {
+///         let request = HttpRequest::new(HttpMethod::Get, "https://example.com/scores")
+///             .with_header("Accept", "application/json");
+///         self.job = Some(HttpRequestJob::new(request));
+///     }
+///
+///     fn poll(&mut self) {
+///         if let Some(job) = &mut self.job {
+///             match job.try_poll() {
+///                 Ok(Some(response)) if response.status == 200 => self.scores = response.body,
+///                 Ok(Some(response)) => println!("Unexpected status: {}", response.status),
+///                 Ok(None) => return,
+///                 Err(err) => println!("Request has failed: {err}"),
+///             }
+///             self.job = None;
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct HttpRequestJob {
+    inner: Job<Result<HttpResponse, JobError>>,
+}
+
+impl HttpRequestJob {
+    /// Creates a new job to send a `request`.
+    ///
+    /// # Platform-specific
+    ///
+    /// - Web: the request is sent using the browser `fetch` API.
+    /// - Other: the request is sent using the `ureq` crate.
+    pub fn new(request: HttpRequest) -> Self {
+        Self {
+            inner: Job::new(platform::send_http_request(request)),
+        }
+    }
+
+    /// Try polling the job result.
+    ///
+    /// `None` is returned if the response is not yet received or if the result has already been
+    /// retrieved.
+    ///
+    /// Note that a response with a status different from `2xx` is not considered as an error.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the request has not been successfully sent or if the job has been
+    /// cancelled.
+    pub fn try_poll(&mut self) -> Result<Option<HttpResponse>, JobError> {
+        self.inner.try_poll()?.transpose()
+    }
+
+    /// Cancels the job.
+    ///
+    /// If the request has already been sent, the response is ignored.
+    ///
+    /// This method has no effect if the job has already finished.
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    /// Returns a token that can be used to cancel the job from another place.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.inner.cancellation_token()
+    }
+}
+
+/// An HTTP request.
+///
+/// # Examples
+///
+/// See [`HttpRequestJob`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub(crate) method: HttpMethod,
+    pub(crate) url: String,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) body: Vec<u8>,
+}
+
+impl HttpRequest {
+    /// Creates a new request without header and body.
+    pub fn new(method: HttpMethod, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: vec![],
+            body: vec![],
+        }
+    }
+
+    /// Returns the request with an additional header.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Returns the request with a different body.
+    pub fn with_body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+}
+
+/// The method of an [`HttpRequest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HttpMethod {
+    /// `GET` method.
+    Get,
+    /// `HEAD` method.
+    Head,
+    /// `POST` method.
+    Post,
+    /// `PUT` method.
+    Put,
+    /// `PATCH` method.
+    Patch,
+    /// `DELETE` method.
+    Delete,
+}
+
+impl HttpMethod {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Head => "HEAD",
+            Self::Post => "POST",
+            Self::Put => "PUT",
+            Self::Patch => "PATCH",
+            Self::Delete => "DELETE",
+        }
+    }
+}
+
+/// The response of an [`HttpRequest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The response body.
+    pub body: Vec<u8>,
+}
@@ -0,0 +1,105 @@
+use crate::{platform, CancellationToken, Job, JobError, VariableSend};
+use instant::Instant;
+use std::any::Any;
+use std::time::Duration;
+
+/// An asynchronous job that periodically runs a closure until it is stopped.
+///
+/// The closure is first run once the interval has elapsed after the job creation.
+///
+/// Runs never overlap: if a run lasts longer than the interval, the missed ticks are skipped and
+/// the next run happens at the next tick.
+///
+/// # Examples
+///
+/// ```rust
+/// # use modor::*;
+/// # use modor_jobs::*;
+/// # use std::time::Duration;
+/// #
+/// struct AutoSave {
+///     job: IntervalJob,
+/// }
+///
+/// impl AutoSave {
+///     fn new() -> Self {
+///         Self {
+///             job: IntervalJob::new(Duration::from_secs(60), || println!("Game saved")),
+///         }
+///     }
+///
+///     fn disable(&self) {
+///         self.job.stop();
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct IntervalJob {
+    inner: Job<()>,
+    is_stopped: bool,
+}
+
+impl IntervalJob {
+    /// Creates a new job that runs `f` every `interval`.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `interval` is zero.
+    ///
+    /// # Platform-specific
+    ///
+    /// - Web: `f` is run in the main thread.
+    pub fn new(interval: Duration, mut f: impl FnMut() + VariableSend + Any) -> Self {
+        assert!(
+            !interval.is_zero(),
+            "job interval must be greater than zero"
+        );
+        Self {
+            inner: Job::new(async move {
+                let mut next_run = Instant::now() + interval;
+                loop {
+                    platform::sleep(next_run.saturating_duration_since(Instant::now())).await;
+                    f();
+                    let now = Instant::now();
+                    while next_run <= now {
+                        next_run += interval;
+                    }
+                }
+            }),
+            is_stopped: false,
+        }
+    }
+
+    /// Stops the job.
+    ///
+    /// If the closure is currently running, the run is not interrupted but no other run happens.
+    pub fn stop(&self) {
+        self.inner.cancel();
+    }
+
+    /// Returns a token that can be used to stop the job from another place.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.inner.cancellation_token()
+    }
+
+    /// Try polling the job status.
+    ///
+    /// `true` is returned if the job has been stopped, `false` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the closure has panicked.
+    pub fn try_poll(&mut self) -> Result<bool, JobError> {
+        if self.is_stopped {
+            return Ok(true);
+        }
+        match self.inner.try_poll() {
+            Ok(_) => Ok(false),
+            Err(JobError::Cancelled) => {
+                self.is_stopped = true;
+                Ok(true)
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
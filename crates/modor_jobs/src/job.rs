@@ -2,11 +2,15 @@ use crate::platform;
 use crate::platform::{JobFutureJoinHandle, VariableSend};
 use futures::channel::oneshot;
 use futures::channel::oneshot::{Receiver, Sender};
+use futures::future::{AbortHandle, Abortable};
+use futures::FutureExt;
 use std::any;
 use std::any::Any;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex};
 
 /// An asynchronous job.
 ///
@@ -55,7 +59,9 @@ use std::future::Future;
 /// ```
 #[derive(Debug)]
 pub struct Job<T> {
-    receiver: Option<Receiver<T>>,
+    receiver: Option<Receiver<Result<T, JobError>>>,
+    cancellation: CancellationToken,
+    progress: JobProgress,
     _join: JobFutureJoinHandle<()>,
 }
 
@@ -65,12 +71,107 @@ where
 {
     /// Creates a new job to run a `future`.
     ///
+    /// If the [`Job`](Job) is dropped before the future has finished, the produced value is
+    /// discarded.
+    pub fn new(future: impl JobFuture<T>) -> Self {
+        Self::from_fallible(future.map(Ok), JobProgress::default())
+    }
+
+    /// Creates a new job to run the future returned by `f`.
+    ///
+    /// The [`JobProgress`] passed to `f` can be used by the future to report its progress,
+    /// which can then be retrieved with [`Job::progress`].
+    ///
+    /// If the [`Job`](Job) is dropped before the future has finished, the produced value is
+    /// discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use modor_jobs::*;
+    /// #
+    /// fn sum(values: Vec<u64>) -> Job<u64> {
+    ///     Job::with_progress(|progress| async move {
+    ///         let mut sum = 0;
+    ///         for (index, value) in values.iter().enumerate() {
+    ///             sum += value;
+    ///             progress.set(index as u64 + 1, values.len() as u64);
+    ///         }
+    ///         sum
+    ///     })
+    /// }
+    /// ```
+    pub fn with_progress<F>(f: impl FnOnce(JobProgress) -> F) -> Self
+    where
+        F: JobFuture<T>,
+    {
+        let progress = JobProgress::default();
+        Self::from_fallible(f(progress.clone()).map(Ok), progress)
+    }
+
+    /// Creates a job that runs `f` with the value produced by this job.
+    ///
+    /// The returned job fails if any of the two stages fails.
+    ///
+    /// Cancelling the returned job cancels the stage currently running.
+    ///
     /// # Panics
     ///
-    /// The future will panic if the [`Job`](Job) is dropped before the future has finished.
-    pub fn new(future: impl JobFuture<T>) -> Self {
+    /// This will panic if the result of this job has already been retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use modor_jobs::*;
+    /// #
+    /// fn parse_number() -> Job<u32> {
+    ///     Job::new(async { b"42".to_vec() })
+    ///         .then(|bytes| async move { String::from_utf8_lossy(&bytes).parse().unwrap_or(0) })
+    /// }
+    /// ```
+    pub fn then<U, F>(self, f: impl FnOnce(T) -> F + VariableSend + Any) -> Job<U>
+    where
+        U: Any + VariableSend,
+        F: JobFuture<U>,
+    {
+        let (result, cancellation) = self.into_result();
+        let mut job = Job::from_fallible(
+            async move { Ok(f(result.await?).await) },
+            JobProgress::default(),
+        );
+        job.cancellation.0.extend(cancellation.0);
+        job
+    }
+
+    pub(crate) fn join(jobs: Vec<Self>) -> Job<Vec<T>> {
+        let (results, cancellations): (Vec<_>, Vec<_>) =
+            jobs.into_iter().map(Self::into_result).unzip();
+        let mut job = Job::from_fallible(
+            async move {
+                let mut values = Vec::with_capacity(results.len());
+                for result in results {
+                    values.push(result.await?);
+                }
+                Ok(values)
+            },
+            JobProgress::default(),
+        );
+        job.cancellation
+            .0
+            .extend(cancellations.into_iter().flat_map(|c| c.0));
+        job
+    }
+
+    fn into_result(mut self) -> (impl JobFuture<Result<T, JobError>>, CancellationToken) {
+        let receiver = self.receiver.take().expect("job result already retrieved");
+        let result = async move { receiver.await.unwrap_or(Err(JobError::Panicked(None))) };
+        (result, self.cancellation)
+    }
+
+    fn from_fallible(future: impl JobFuture<Result<T, JobError>>, progress: JobProgress) -> Self {
         let (sender, receiver) = oneshot::channel();
-        let job = Self::job_future(future, sender);
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
+        let job = Self::job_future(Abortable::new(future, abort_registration), sender);
         let join = platform::spawn_future(job);
         debug!(
             "Job producing value of type `{}` has started", // no-coverage
@@ -78,16 +179,56 @@ where
         );
         Self {
             receiver: Some(receiver),
+            cancellation: CancellationToken(vec![abort_handle]),
+            progress,
             _join: join,
         }
     }
 
     #[allow(clippy::future_not_send)]
-    async fn job_future(future: impl JobFuture<T>, sender: Sender<T>) {
-        sender
-            .send(future.await)
-            .ok()
-            .expect("job dropped before future finishes");
+    async fn job_future(
+        future: Abortable<impl JobFuture<Result<T, JobError>>>,
+        sender: Sender<Result<T, JobError>>,
+    ) {
+        let result = match AssertUnwindSafe(future).catch_unwind().await {
+            Ok(result) => result.unwrap_or(Err(JobError::Cancelled)),
+            Err(payload) => {
+                debug!(
+                    "Job producing value of type `{}` has panicked", // no-coverage
+                    any::type_name::<T>()                            // no-coverage
+                );
+                Err(JobError::Panicked(panic_message(payload.as_ref())))
+            }
+        };
+        if sender.send(result).is_err() {
+            debug!(
+                "Job producing value of type `{}` dropped before the end", // no-coverage
+                any::type_name::<T>()                                      // no-coverage
+            );
+        }
+    }
+
+    /// Cancels the job.
+    ///
+    /// The future run by the job is stopped at its next `.await` point, and
+    /// [`Job::try_poll`] then returns [`JobError::Cancelled`].
+    ///
+    /// This method has no effect if the job has already finished.
+    pub fn cancel(&self) {
+        self.cancellation.cancel();
+    }
+
+    /// Returns a token that can be used to cancel the job from another place.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation.clone()
+    }
+
+    /// Returns the latest progress reported by the job as `(done, total)`.
+    ///
+    /// `None` is returned if the job has not reported any progress, which is always the case
+    /// for jobs not created with [`Job::with_progress`].
+    pub fn progress(&self) -> Option<(u64, u64)> {
+        self.progress.get()
     }
 
     /// Try polling the job result.
@@ -96,10 +237,15 @@ where
     ///
     /// # Errors
     ///
-    /// An error is returned if the future run by a [`Job`](Job) has panicked.
-    pub fn try_poll(&mut self) -> Result<Option<T>, JobPanickedError> {
+    /// An error is returned if the future run by a [`Job`](Job) has panicked or if the job has
+    /// been cancelled.
+    pub fn try_poll(&mut self) -> Result<Option<T>, JobError> {
         if let Some(receiver) = &mut self.receiver {
-            let result = receiver.try_recv().map_err(|_| JobPanickedError);
+            let result = match receiver.try_recv() {
+                Ok(Some(result)) => result.map(Some),
+                Ok(None) => Ok(None),
+                Err(_) => Err(JobError::Panicked(None)),
+            };
             if let Ok(Some(_)) | Err(_) = &result {
                 self.receiver = None;
                 debug!(
@@ -123,19 +269,104 @@ where
     }
 }
 
-/// An error occurring when the future run by a [`Job`](Job) panics.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct JobPanickedError;
+/// A token to cancel a [`Job`](Job).
+///
+/// The token can be cloned to cancel the job from multiple places.
+#[derive(Debug, Clone)]
+pub struct CancellationToken(Vec<AbortHandle>);
+
+impl CancellationToken {
+    /// Cancels the associated job.
+    ///
+    /// This method has no effect if the job has already finished.
+    pub fn cancel(&self) {
+        for handle in &self.0 {
+            handle.abort();
+        }
+    }
+
+    /// Returns whether the associated job has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.iter().any(AbortHandle::is_aborted)
+    }
+}
+
+/// A handle used by a [`Job`](Job) to report its progress.
+///
+/// # Examples
+///
+/// See [`Job::with_progress`].
+#[derive(Debug, Clone, Default)]
+pub struct JobProgress(Arc<Mutex<Option<(u64, u64)>>>);
+
+impl JobProgress {
+    /// Reports that `done` units of work have been performed over a `total` of units.
+    pub fn set(&self, done: u64, total: u64) {
+        *self.0.lock().expect("cannot lock job progress") = Some((done, total));
+    }
+
+    fn get(&self) -> Option<(u64, u64)> {
+        *self.0.lock().expect("cannot lock job progress")
+    }
+}
+
+/// An error occurring when running a job.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum JobError {
+    /// I/O error occurred while running the job.
+    Io(String),
+    /// The resource accessed by the job (e.g. a file) has not been found.
+    ///
+    /// The value is the path of the resource.
+    NotFound(String),
+    /// The job has been cancelled.
+    Cancelled,
+    /// The job has not finished before its timeout.
+    TimedOut,
+    /// The job is not supported on the current platform.
+    UnsupportedPlatform,
+    /// All the sources of the job have failed.
+    ///
+    /// The value contains the error of each source, in the same order as the sources.
+    AllSourcesFailed(Vec<Self>),
+    /// The future run by the job has panicked.
+    ///
+    /// The value is the panic message if it can be retrieved.
+    Panicked(Option<String>),
+}
 
 // coverage: off (not necessary to test Display impl)
-impl Display for JobPanickedError {
+impl Display for JobError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "job has panicked")
+        match self {
+            Self::Io(m) => write!(f, "IO error: {m}"),
+            Self::NotFound(p) => write!(f, "`{p}` not found"),
+            Self::Cancelled => write!(f, "job has been cancelled"),
+            Self::TimedOut => write!(f, "job has timed out"),
+            Self::UnsupportedPlatform => write!(f, "job not supported on this platform"),
+            Self::AllSourcesFailed(errors) => {
+                write!(f, "all sources have failed")?;
+                for (index, error) in errors.iter().enumerate() {
+                    write!(f, "{} {error}", if index == 0 { ":" } else { ";" })?;
+                }
+                Ok(())
+            }
+            Self::Panicked(Some(m)) => write!(f, "job has panicked: {m}"),
+            Self::Panicked(None) => write!(f, "job has panicked"),
+        }
     }
 }
 // coverage: on
 
-impl Error for JobPanickedError {}
+impl Error for JobError {}
+
+fn panic_message(payload: &(dyn Any + Send)) -> Option<String> {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| (*message).to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+}
 
 /// A trait implemented for any future runnable by a job that produces a value of type `T`.
 pub trait JobFuture<T>: Future<Output = T> + VariableSend + Any {}
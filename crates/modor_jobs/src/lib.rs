@@ -13,9 +13,21 @@
 extern crate log;
 
 mod asset_loading_job;
+mod file_write_job;
+mod http_request_job;
+mod interval_job;
 mod job;
+mod parallel;
 mod platform;
+mod semaphore;
+mod thread_pool;
 
 pub use asset_loading_job::*;
+pub use file_write_job::*;
+pub use http_request_job::*;
+pub use interval_job::*;
 pub use job::*;
+pub use parallel::*;
 pub use platform::*;
+pub use semaphore::*;
+pub use thread_pool::*;
@@ -0,0 +1,48 @@
+use crate::{platform, Job, VariableSend};
+use std::any::Any;
+use std::sync::Arc;
+
+/// Creates a job that applies `f` on each item of `items` in parallel.
+///
+/// The items are split in chunks that are run on the different job threads (see
+/// [`thread_count`](crate::thread_count)).
+///
+/// The produced values are in the same order as `items`.
+///
+/// If `f` panics for any item, the job fails with [`JobError::Panicked`](crate::JobError).
+///
+/// # Platform-specific
+///
+/// - Web: all items are processed in the main thread.
+///
+/// # Examples
+///
+/// ```rust
+/// # use modor_jobs::*;
+/// #
+/// fn squares(max: u64) -> Job<Vec<u64>> {
+///     modor_jobs::parallel_map(0..max, |value| value * value)
+/// }
+/// ```
+pub fn parallel_map<T, R>(
+    items: impl IntoIterator<Item = T>,
+    f: impl Fn(T) -> R + Send + Sync + 'static,
+) -> Job<Vec<R>>
+where
+    T: Any + VariableSend,
+    R: Any + VariableSend,
+{
+    let mut items: Vec<_> = items.into_iter().collect();
+    let chunk_size = items.len().div_ceil(platform::pool_thread_count()).max(1);
+    let f = Arc::new(f);
+    let mut jobs = vec![];
+    while !items.is_empty() {
+        let chunk: Vec<_> = items.drain(..chunk_size.min(items.len())).collect();
+        let f = f.clone();
+        jobs.push(Job::new(async move {
+            chunk.into_iter().map(|item| f(item)).collect::<Vec<_>>()
+        }));
+    }
+    let job = Job::join(jobs);
+    job.then(|chunks| async { chunks.into_iter().flatten().collect() })
+}
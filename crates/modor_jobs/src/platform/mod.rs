@@ -1,8 +1,17 @@
 #[cfg(target_os = "android")]
-include!("android.rs");
-
+mod android;
+#[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
+mod not_android_wasm;
+#[cfg(not(target_arch = "wasm32"))]
+mod not_wasm;
 #[cfg(target_arch = "wasm32")]
-include!("wasm.rs");
+mod wasm;
 
+#[cfg(target_os = "android")]
+pub use android::*;
 #[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
-include!("not_android_wasm.rs");
+pub use not_android_wasm::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use not_wasm::*;
+#[cfg(target_arch = "wasm32")]
+pub use wasm::*;
@@ -0,0 +1,75 @@
+use crate::{HttpRequest, HttpResponse, JobError, JobFuture};
+use futures::executor::ThreadPool;
+use std::io::Read;
+use std::marker::PhantomData;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+static THREAD_COUNT: AtomicUsize = AtomicUsize::new(0);
+static THREAD_POOL: Mutex<Option<ThreadPool>> = Mutex::new(None);
+
+pub(crate) type JobFutureJoinHandle<T> = PhantomData<T>;
+
+pub(crate) fn spawn_future(future: impl JobFuture<()>) -> JobFutureJoinHandle<()> {
+    THREAD_POOL
+        .lock()
+        .expect("cannot lock job thread pool")
+        .get_or_insert_with(|| {
+            ThreadPool::builder()
+                .pool_size(pool_thread_count())
+                .name_prefix("modor-job-")
+                .create()
+                .expect("cannot create job thread pool")
+        })
+        .spawn_ok(future);
+    PhantomData
+}
+
+pub(crate) fn set_pool_thread_count(count: usize) {
+    assert!(count > 0, "job thread count must be greater than zero");
+    THREAD_COUNT.store(count, Ordering::Relaxed);
+    // running jobs keep the previous pool alive until they finish
+    *THREAD_POOL.lock().expect("cannot lock job thread pool") = None;
+}
+
+pub(crate) fn pool_thread_count() -> usize {
+    match THREAD_COUNT.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        count => count,
+    }
+}
+
+pub(crate) async fn sleep(duration: Duration) {
+    async_std::task::sleep(duration).await;
+}
+
+pub(crate) async fn write_file(path: PathBuf, bytes: Vec<u8>) -> Result<(), JobError> {
+    async_std::fs::write(path, bytes)
+        .await
+        .map_err(|e| JobError::Io(e.to_string()))
+}
+
+pub(crate) async fn send_http_request(request: HttpRequest) -> Result<HttpResponse, JobError> {
+    async_std::task::spawn_blocking(move || {
+        let mut http_request = ureq::request(request.method.as_str(), &request.url);
+        for (name, value) in &request.headers {
+            http_request = http_request.set(name, value);
+        }
+        let response = match http_request.send_bytes(&request.body) {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => return Err(JobError::Io(err.to_string())),
+        };
+        let status = response.status();
+        let mut body = vec![];
+        response
+            .into_reader()
+            .read_to_end(&mut body)
+            .map_err(|e| JobError::Io(e.to_string()))?;
+        Ok(HttpResponse { status, body })
+    })
+    .await
+}
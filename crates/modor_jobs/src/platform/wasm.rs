@@ -1,5 +1,9 @@
-use crate::{AssetLoadingError, JobFuture, ASSET_FOLDER_NAME};
+use crate::{HttpRequest, HttpResponse, JobError, JobFuture, ASSET_FOLDER_NAME};
 use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::time::Duration;
+use wasm_bindgen_futures::js_sys::{Function, Promise};
+use wasm_bindgen_futures::JsFuture;
 
 /// A trait implemented for any type implementing [`Send`], or implemented for any type on Web
 /// platform.
@@ -14,21 +18,71 @@ pub(crate) fn spawn_future(future: impl JobFuture<()>) -> JobFutureJoinHandle<()
     PhantomData
 }
 
+pub(crate) fn set_pool_thread_count(count: usize) {
+    assert!(count > 0, "job thread count must be greater than zero");
+}
+
+pub(crate) fn pool_thread_count() -> usize {
+    1
+}
+
 #[allow(clippy::future_not_send)]
-pub(crate) async fn load_asset(path: String) -> Result<Vec<u8>, AssetLoadingError> {
+pub(crate) async fn load_asset(path: String) -> Result<Vec<u8>, JobError> {
     let base_url = web_sys::window()
-        .ok_or(AssetLoadingError::NotFoundDomWindow)?
+        .ok_or_else(|| JobError::Io("DOM window not found".into()))?
         .location()
         .href()
-        .map_err(|e| AssetLoadingError::InvalidLocationHref(format!("{e:?}")))?;
+        .map_err(|e| JobError::Io(format!("invalid location.href property: {e:?}")))?;
     let url = format!("{base_url}/{ASSET_FOLDER_NAME}/{path}");
-    reqwest::get(url)
+    let response = reqwest::get(url)
         .await
-        .map_err(|e| AssetLoadingError::IoError(e.to_string()))?
+        .map_err(|e| JobError::Io(e.to_string()))?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err(JobError::NotFound(path));
+    }
+    response
         .error_for_status()
-        .map_err(|e| AssetLoadingError::IoError(e.to_string()))?
+        .map_err(|e| JobError::Io(e.to_string()))?
         .bytes()
         .await
-        .map_err(|e| AssetLoadingError::IoError(e.to_string()))
+        .map_err(|e| JobError::Io(e.to_string()))
         .map(Into::into)
 }
+
+#[allow(clippy::future_not_send)]
+pub(crate) async fn sleep(duration: Duration) {
+    let timeout = i32::try_from(duration.as_millis()).unwrap_or(i32::MAX);
+    let promise = Promise::new(&mut |resolve: Function, _| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, timeout);
+        }
+    });
+    let _ = JsFuture::from(promise).await;
+}
+
+#[allow(clippy::unused_async)]
+pub(crate) async fn write_file(_path: PathBuf, _bytes: Vec<u8>) -> Result<(), JobError> {
+    Err(JobError::UnsupportedPlatform)
+}
+
+#[allow(clippy::future_not_send)]
+pub(crate) async fn send_http_request(request: HttpRequest) -> Result<HttpResponse, JobError> {
+    let method = reqwest::Method::from_bytes(request.method.as_str().as_bytes())
+        .map_err(|e| JobError::Io(e.to_string()))?;
+    let mut http_request = reqwest::Client::new().request(method, request.url);
+    for (name, value) in request.headers {
+        http_request = http_request.header(name, value);
+    }
+    let response = http_request
+        .body(request.body)
+        .send()
+        .await
+        .map_err(|e| JobError::Io(e.to_string()))?;
+    let status = response.status().as_u16();
+    let body = response
+        .bytes()
+        .await
+        .map_err(|e| JobError::Io(e.to_string()))?
+        .into();
+    Ok(HttpResponse { status, body })
+}
@@ -0,0 +1,142 @@
+use crate::JobFuture;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// A limiter of the number of jobs that run simultaneously.
+///
+/// The futures limited by a semaphore beyond its limit are queued until another limited future
+/// finishes.
+///
+/// A semaphore can be cloned to share the same limit between multiple places.
+///
+/// # Examples
+///
+/// ```rust
+/// # use modor_jobs::*;
+/// #
+/// fn load_all(semaphore: &JobSemaphore, paths: Vec<String>) -> Vec<Job<Vec<u8>>> {
+///     paths
+///         .into_iter()
+///         .map(|path| Job::new(semaphore.limit(async { std::fs::read(path).unwrap() })))
+///         .collect()
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct JobSemaphore {
+    state: Arc<Mutex<SemaphoreState>>,
+}
+
+impl JobSemaphore {
+    /// Creates a new semaphore allowing `limit` futures to run simultaneously.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `limit` is zero.
+    pub fn new(limit: usize) -> Self {
+        assert!(limit > 0, "job semaphore limit must be greater than zero");
+        Self {
+            state: Arc::new(Mutex::new(SemaphoreState {
+                available_permits: limit,
+                waiters: VecDeque::new(),
+                next_waiter_id: 0,
+            })),
+        }
+    }
+
+    /// Returns a future that runs `future` once a slot of the semaphore is available.
+    ///
+    /// The slot is released when `future` finishes or when the returned future is dropped
+    /// (e.g. if the job running it is cancelled).
+    pub fn limit<T>(&self, future: impl JobFuture<T>) -> impl JobFuture<T> {
+        let semaphore = self.clone();
+        async move {
+            let _permit = semaphore.acquire().await;
+            future.await
+        }
+    }
+
+    fn acquire(&self) -> Acquire {
+        let mut state = self.lock();
+        let id = state.next_waiter_id;
+        state.next_waiter_id += 1;
+        Acquire {
+            semaphore: self.clone(),
+            id,
+            is_acquired: false,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, SemaphoreState> {
+        self.state.lock().expect("cannot lock job semaphore")
+    }
+}
+
+#[derive(Debug)]
+struct SemaphoreState {
+    available_permits: usize,
+    waiters: VecDeque<(u64, Waker)>,
+    next_waiter_id: u64,
+}
+
+impl SemaphoreState {
+    fn wake_next_waiter(&mut self) {
+        if self.available_permits > 0 {
+            if let Some((_, waker)) = self.waiters.pop_front() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+struct Acquire {
+    semaphore: JobSemaphore,
+    id: u64,
+    is_acquired: bool,
+}
+
+impl Future for Acquire {
+    type Output = Permit;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let semaphore = self.semaphore.clone();
+        let mut state = semaphore.lock();
+        let id = self.id;
+        state.waiters.retain(|(waiter_id, _)| *waiter_id != id);
+        if state.available_permits > 0 {
+            state.available_permits -= 1;
+            self.is_acquired = true;
+            Poll::Ready(Permit {
+                semaphore: semaphore.clone(),
+            })
+        } else {
+            state.waiters.push_back((id, cx.waker().clone()));
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for Acquire {
+    fn drop(&mut self) {
+        if !self.is_acquired {
+            let mut state = self.semaphore.lock();
+            let id = self.id;
+            state.waiters.retain(|(waiter_id, _)| *waiter_id != id);
+            state.wake_next_waiter();
+        }
+    }
+}
+
+struct Permit {
+    semaphore: JobSemaphore,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        let mut state = self.semaphore.lock();
+        state.available_permits += 1;
+        state.wake_next_waiter();
+    }
+}
@@ -0,0 +1,36 @@
+use crate::platform;
+
+/// Sets the number of threads used to run jobs.
+///
+/// By default, the number of threads is equal to the number of available CPUs.
+///
+/// Jobs already running are not impacted, only new jobs are run with the new thread count.
+///
+/// # Platform-specific
+///
+/// - Web: jobs are always run in the main thread, so this function has no effect.
+///
+/// # Panics
+///
+/// This will panic if `count` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use modor_jobs::*;
+/// #
+/// modor_jobs::set_thread_count(2);
+/// let job = Job::new(async { 42 });
+/// ```
+pub fn set_thread_count(count: usize) {
+    platform::set_pool_thread_count(count);
+}
+
+/// Returns the number of threads used to run jobs.
+///
+/// # Platform-specific
+///
+/// - Web: `1` is always returned.
+pub fn thread_count() -> usize {
+    platform::pool_thread_count()
+}
@@ -1,5 +1,6 @@
-use modor_jobs::{AssetLoadingError, AssetLoadingJob};
+use modor_jobs::{AssetLoadingJob, AssetSource, JobError};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
 use std::thread;
 use std::time::Duration;
@@ -30,9 +31,107 @@ fn load_valid_file_without_cargo() {
 
 #[modor::test(disabled(wasm))]
 fn load_missing_file() {
+    let _lock = CARGO_MANIFEST_DIR_LOCK.lock();
     let mut job = AssetLoadingJob::new("invalid.txt", file_size);
     let result = retrieve_result(&mut job);
-    assert!(matches!(result, Err(AssetLoadingError::IoError(_))));
+    assert_eq!(result, Err(JobError::NotFound("invalid.txt".into())));
+    assert_eq!(job.try_poll(), Ok(None));
+}
+
+#[modor::test(disabled(wasm))]
+fn load_from_sources_with_first_success() {
+    let _lock = CARGO_MANIFEST_DIR_LOCK.lock();
+    let sources = vec![
+        AssetSource::Path("test.txt".into()),
+        AssetSource::Path("invalid.txt".into()),
+    ];
+    let mut job = AssetLoadingJob::from_sources(sources, file_size);
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Ok(Some(12)));
+    assert_eq!(job.try_poll(), Ok(None));
+}
+
+#[modor::test(disabled(wasm))]
+fn load_from_sources_with_fallback() {
+    let _lock = CARGO_MANIFEST_DIR_LOCK.lock();
+    let sources = vec![
+        AssetSource::Path("invalid.txt".into()),
+        AssetSource::Path("test.txt".into()),
+    ];
+    let mut job = AssetLoadingJob::from_sources(sources, file_size);
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Ok(Some(12)));
+    assert_eq!(job.try_poll(), Ok(None));
+}
+
+#[modor::test(disabled(wasm))]
+fn load_from_failing_sources() {
+    let _lock = CARGO_MANIFEST_DIR_LOCK.lock();
+    let sources = vec![
+        AssetSource::Path("invalid.txt".into()),
+        AssetSource::Url("http://127.0.0.1:1/invalid.txt".into()),
+    ];
+    let mut job = AssetLoadingJob::from_sources(sources, file_size);
+    let result = retrieve_result(&mut job);
+    let Err(JobError::AllSourcesFailed(errors)) = result else {
+        panic!("unexpected result: {result:?}")
+    };
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0], JobError::NotFound("invalid.txt".into()));
+    assert!(matches!(errors[1], JobError::Io(_)));
+    assert_eq!(job.try_poll(), Ok(None));
+}
+
+#[modor::test(disabled(wasm))]
+fn load_from_no_source() {
+    let _lock = CARGO_MANIFEST_DIR_LOCK.lock();
+    let mut job = AssetLoadingJob::from_sources(vec![], file_size);
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Err(JobError::AllSourcesFailed(vec![])));
+}
+
+#[modor::test(disabled(wasm))]
+fn cancel_loading() {
+    let _lock = CARGO_MANIFEST_DIR_LOCK.lock();
+    let mut job = AssetLoadingJob::new("test.txt", slow_file_size);
+    job.cancel();
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Err(JobError::Cancelled));
+    assert_eq!(job.try_poll(), Ok(None));
+}
+
+#[modor::test(disabled(wasm))]
+fn load_with_timeout_reached() {
+    let _lock = CARGO_MANIFEST_DIR_LOCK.lock();
+    let mut job =
+        AssetLoadingJob::new("test.txt", slow_file_size).with_timeout(Duration::from_millis(50));
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Err(JobError::TimedOut));
+    assert_eq!(job.try_poll(), Ok(None));
+}
+
+#[modor::test(disabled(wasm))]
+fn load_with_timeout_reached_without_polling() {
+    static IS_FINISHED: AtomicBool = AtomicBool::new(false);
+    let _lock = CARGO_MANIFEST_DIR_LOCK.lock();
+    let mut job = AssetLoadingJob::new("test.txt", |bytes| async move {
+        async_std::task::sleep(Duration::from_millis(200)).await;
+        IS_FINISHED.store(true, Ordering::SeqCst);
+        bytes.len()
+    })
+    .with_timeout(Duration::from_millis(50));
+    thread::sleep(Duration::from_millis(400));
+    assert!(!IS_FINISHED.load(Ordering::SeqCst));
+    assert_eq!(job.try_poll(), Err(JobError::TimedOut));
+    assert_eq!(job.try_poll(), Ok(None));
+}
+
+#[modor::test(disabled(wasm))]
+fn load_with_timeout_not_reached() {
+    let _lock = CARGO_MANIFEST_DIR_LOCK.lock();
+    let mut job = AssetLoadingJob::new("test.txt", file_size).with_timeout(Duration::from_secs(10));
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Ok(Some(12)));
     assert_eq!(job.try_poll(), Ok(None));
 }
 
@@ -43,7 +142,14 @@ async fn file_size(bytes: Vec<u8>) -> usize {
     bytes.len()
 }
 
-fn retrieve_result(job: &mut AssetLoadingJob<usize>) -> Result<Option<usize>, AssetLoadingError> {
+#[allow(clippy::unused_async)]
+async fn slow_file_size(bytes: Vec<u8>) -> usize {
+    #[cfg(not(target_arch = "wasm32"))]
+    async_std::task::sleep(Duration::from_secs(10)).await;
+    bytes.len()
+}
+
+fn retrieve_result(job: &mut AssetLoadingJob<usize>) -> Result<Option<usize>, JobError> {
     const MAX_RETRIES: u32 = 100;
     for _ in 0..MAX_RETRIES {
         thread::sleep(Duration::from_millis(10));
@@ -0,0 +1,41 @@
+use modor_jobs::{FileWriteJob, JobError};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+use std::{env, fs};
+
+#[modor::test(disabled(wasm))]
+fn write_valid_file() {
+    let path = temporary_path("modor_jobs_write_valid_file.txt");
+    let mut job = FileWriteJob::new(&path, b"file content".to_vec());
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Ok(Some(())));
+    assert_eq!(job.try_poll(), Ok(None));
+    assert_eq!(fs::read(&path).unwrap(), b"file content");
+    fs::remove_file(path).unwrap();
+}
+
+#[modor::test(disabled(wasm))]
+fn write_file_in_missing_folder() {
+    let path = temporary_path("modor_jobs_missing_folder/file.txt");
+    let mut job = FileWriteJob::new(path, b"file content".to_vec());
+    let result = retrieve_result(&mut job);
+    assert!(matches!(result, Err(JobError::Io(_))));
+    assert_eq!(job.try_poll(), Ok(None));
+}
+
+fn temporary_path(name: &str) -> PathBuf {
+    env::temp_dir().join(name)
+}
+
+fn retrieve_result(job: &mut FileWriteJob) -> Result<Option<()>, JobError> {
+    const MAX_RETRIES: u32 = 100;
+    for _ in 0..MAX_RETRIES {
+        thread::sleep(Duration::from_millis(10));
+        let result = job.try_poll();
+        if result != Ok(None) {
+            return result;
+        }
+    }
+    panic!("max retries reached");
+}
@@ -0,0 +1,113 @@
+use modor_jobs::{HttpMethod, HttpRequest, HttpRequestJob, HttpResponse, JobError};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+#[modor::test(disabled(wasm))]
+fn send_get_request() {
+    let (url, server) = start_server(200);
+    let request = HttpRequest::new(HttpMethod::Get, url).with_header("X-Custom", "value");
+    let mut job = HttpRequestJob::new(request);
+    let result = retrieve_result(&mut job);
+    assert_eq!(
+        result,
+        Ok(Some(HttpResponse {
+            status: 200,
+            body: b"GET /path|value|".to_vec(),
+        }))
+    );
+    assert_eq!(job.try_poll(), Ok(None));
+    server.join().unwrap();
+}
+
+#[modor::test(disabled(wasm))]
+fn send_post_request() {
+    let (url, server) = start_server(201);
+    let request = HttpRequest::new(HttpMethod::Post, url)
+        .with_header("X-Custom", "value")
+        .with_body("content");
+    let mut job = HttpRequestJob::new(request);
+    let result = retrieve_result(&mut job);
+    assert_eq!(
+        result,
+        Ok(Some(HttpResponse {
+            status: 201,
+            body: b"POST /path|value|content".to_vec(),
+        }))
+    );
+    server.join().unwrap();
+}
+
+#[modor::test(disabled(wasm))]
+fn send_request_with_error_status() {
+    let (url, server) = start_server(404);
+    let mut job = HttpRequestJob::new(HttpRequest::new(HttpMethod::Get, url));
+    let result = retrieve_result(&mut job);
+    assert_eq!(result.map(|r| r.map(|r| r.status)), Ok(Some(404)));
+    server.join().unwrap();
+}
+
+#[modor::test(disabled(wasm))]
+fn send_request_to_invalid_url() {
+    let mut job = HttpRequestJob::new(HttpRequest::new(HttpMethod::Get, "invalid"));
+    let result = retrieve_result(&mut job);
+    assert!(matches!(result, Err(JobError::Io(_))));
+}
+
+// The server responds with a body containing the request method, path, `X-Custom` header and body.
+fn start_server(status: u16) -> (String, JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/path", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+        let mut custom_header = String::new();
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            let (name, value) = line.split_once(": ").unwrap();
+            match name.to_lowercase().as_str() {
+                "x-custom" => custom_header = value.into(),
+                "content-length" => content_length = value.parse().unwrap(),
+                _ => (),
+            }
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+        let mut request_parts = request_line.split(' ');
+        let method = request_parts.next().unwrap();
+        let path = request_parts.next().unwrap();
+        let body = format!(
+            "{method} {path}|{custom_header}|{}",
+            String::from_utf8(body).unwrap()
+        );
+        write!(
+            stream,
+            "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+    });
+    (url, server)
+}
+
+fn retrieve_result(job: &mut HttpRequestJob) -> Result<Option<HttpResponse>, JobError> {
+    const MAX_RETRIES: u32 = 500;
+    for _ in 0..MAX_RETRIES {
+        thread::sleep(Duration::from_millis(10));
+        let result = job.try_poll();
+        if result != Ok(None) {
+            return result;
+        }
+    }
+    panic!("max retries reached");
+}
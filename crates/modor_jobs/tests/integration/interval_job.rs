@@ -0,0 +1,48 @@
+use modor_jobs::IntervalJob;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+#[modor::test(disabled(wasm))]
+fn run_periodically() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let job_counter = counter.clone();
+    let mut job = IntervalJob::new(Duration::from_millis(50), move || {
+        job_counter.fetch_add(1, Ordering::SeqCst);
+    });
+    thread::sleep(Duration::from_millis(520));
+    let count = counter.load(Ordering::SeqCst);
+    assert!((7..=10).contains(&count), "unexpected run count: {count}");
+    assert_eq!(job.try_poll(), Ok(false));
+}
+
+#[modor::test(disabled(wasm))]
+fn skip_overlapping_runs() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let job_counter = counter.clone();
+    let _job = IntervalJob::new(Duration::from_millis(10), move || {
+        job_counter.fetch_add(1, Ordering::SeqCst);
+        thread::sleep(Duration::from_millis(100));
+    });
+    thread::sleep(Duration::from_millis(350));
+    let count = counter.load(Ordering::SeqCst);
+    assert!((3..=4).contains(&count), "unexpected run count: {count}");
+}
+
+#[modor::test(disabled(wasm))]
+fn stop_job() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let job_counter = counter.clone();
+    let mut job = IntervalJob::new(Duration::from_millis(10), move || {
+        job_counter.fetch_add(1, Ordering::SeqCst);
+    });
+    thread::sleep(Duration::from_millis(100));
+    job.stop();
+    thread::sleep(Duration::from_millis(20));
+    let count = counter.load(Ordering::SeqCst);
+    thread::sleep(Duration::from_millis(100));
+    assert_eq!(counter.load(Ordering::SeqCst), count);
+    assert_eq!(job.try_poll(), Ok(true));
+    assert_eq!(job.try_poll(), Ok(true));
+}
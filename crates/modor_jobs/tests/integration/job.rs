@@ -1,4 +1,4 @@
-use modor_jobs::{Job, JobPanickedError};
+use modor_jobs::{Job, JobError};
 use std::thread;
 use std::time::Duration;
 
@@ -14,7 +14,48 @@ fn run_successful_job() {
 fn run_failing_job() {
     let mut job = Job::new(file_size("not/existing/path"));
     let result = retrieve_result(&mut job);
-    assert_eq!(result, Err(JobPanickedError));
+    assert!(matches!(result, Err(JobError::Panicked(Some(_)))));
+    assert_eq!(job.try_poll(), Ok(None));
+}
+
+#[modor::test(disabled(wasm))]
+fn run_job_after_panicking_job() {
+    let mut panicking_job = Job::new(async { panic!("job error") });
+    let mut job = Job::new(async { 42 });
+    let result = retrieve_result(&mut panicking_job);
+    assert_eq!(result, Err(JobError::Panicked(Some("job error".into()))));
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Ok(Some(42)));
+}
+
+#[modor::test(disabled(wasm))]
+fn cancel_running_job() {
+    let mut job = Job::new(slow_value());
+    job.cancel();
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Err(JobError::Cancelled));
+    assert_eq!(job.try_poll(), Ok(None));
+}
+
+#[modor::test(disabled(wasm))]
+fn cancel_job_with_token() {
+    let mut job = Job::new(slow_value());
+    let token = job.cancellation_token();
+    assert!(!token.is_cancelled());
+    token.cancel();
+    assert!(token.is_cancelled());
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Err(JobError::Cancelled));
+}
+
+#[modor::test(disabled(wasm))]
+fn cancel_finished_job() {
+    let mut job = Job::new(async { 42 });
+    thread::sleep(Duration::from_millis(100));
+    job.cancel();
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Ok(Some(42)));
+    job.cancel();
     assert_eq!(job.try_poll(), Ok(None));
 }
 
@@ -31,7 +72,94 @@ async fn file_size(path: &str) -> usize {
     }
 }
 
-fn retrieve_result(job: &mut Job<usize>) -> Result<Option<usize>, JobPanickedError> {
+#[modor::test(disabled(wasm))]
+fn run_chained_jobs() {
+    let mut job = Job::new(async { b"42".to_vec() }).then(parse_number);
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Ok(Some(42)));
+    assert_eq!(job.try_poll(), Ok(None));
+}
+
+#[modor::test(disabled(wasm))]
+fn run_chained_jobs_with_failing_first_stage() {
+    let mut job = Job::new(async { panic!("first stage error") }).then(parse_number);
+    let result = retrieve_result(&mut job);
+    assert_eq!(
+        result,
+        Err(JobError::Panicked(Some("first stage error".into())))
+    );
+}
+
+#[modor::test(disabled(wasm))]
+fn run_chained_jobs_with_failing_second_stage() {
+    let mut job = Job::new(async { b"invalid".to_vec() }).then(parse_number);
+    let result = retrieve_result(&mut job);
+    assert!(matches!(result, Err(JobError::Panicked(Some(_)))));
+}
+
+#[modor::test(disabled(wasm))]
+fn cancel_chained_jobs() {
+    let first_job = Job::new(async { slow_value().await.to_string().into_bytes() });
+    let first_token = first_job.cancellation_token();
+    let mut job = first_job.then(parse_number);
+    job.cancel();
+    assert!(first_token.is_cancelled());
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Err(JobError::Cancelled));
+}
+
+#[modor::test(disabled(wasm))]
+fn retrieve_progress() {
+    let mut job = Job::with_progress(|progress| async move {
+        for step in 1..=5 {
+            progress.set(step, 5);
+            #[cfg(not(target_arch = "wasm32"))]
+            async_std::task::sleep(Duration::from_millis(50)).await;
+        }
+        42
+    });
+    let mut progresses = vec![];
+    let mut result = Ok(None);
+    for _ in 0..1000 {
+        thread::sleep(Duration::from_millis(5));
+        if let Some(progress) = job.progress() {
+            if progresses.last() != Some(&progress) {
+                progresses.push(progress);
+            }
+        }
+        result = job.try_poll();
+        if result != Ok(None) {
+            break;
+        }
+    }
+    assert_eq!(result, Ok(Some(42)));
+    assert_eq!(job.progress(), Some((5, 5)));
+    assert!(progresses.len() > 1);
+    assert!(progresses.windows(2).all(|p| p[0].0 < p[1].0));
+}
+
+#[modor::test(disabled(wasm))]
+fn retrieve_progress_when_not_reported() {
+    let mut job = Job::new(async { 42 });
+    assert_eq!(job.progress(), None);
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Ok(Some(42)));
+    assert_eq!(job.progress(), None);
+}
+
+#[allow(clippy::unused_async)]
+async fn parse_number(bytes: Vec<u8>) -> usize {
+    String::from_utf8(bytes).unwrap().parse().unwrap()
+}
+
+#[allow(clippy::unused_async)]
+async fn slow_value() -> usize {
+    #[cfg(not(target_arch = "wasm32"))]
+    async_std::task::sleep(Duration::from_secs(10)).await;
+    42
+}
+
+fn retrieve_result(job: &mut Job<usize>) -> Result<Option<usize>, JobError> {
     const MAX_RETRIES: u32 = 100;
     for _ in 0..MAX_RETRIES {
         thread::sleep(Duration::from_millis(10));
@@ -1,4 +1,9 @@
 #![allow(clippy::unwrap_used)]
 
 pub mod asset_loading_job;
+pub mod file_write_job;
+pub mod http_request_job;
+pub mod interval_job;
 pub mod job;
+pub mod parallel;
+pub mod semaphore;
@@ -0,0 +1,41 @@
+use modor_jobs::{Job, JobError};
+use std::thread;
+use std::time::Duration;
+
+#[modor::test(disabled(wasm))]
+fn map_items_in_parallel() {
+    let mut job = modor_jobs::parallel_map(0..1000, |value| value * 2);
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Ok(Some((0..1000).map(|value| value * 2).collect())));
+    assert_eq!(job.try_poll(), Ok(None));
+}
+
+#[modor::test(disabled(wasm))]
+fn map_no_item_in_parallel() {
+    let mut job = modor_jobs::parallel_map(0..0, |value| value * 2);
+    let result = retrieve_result(&mut job);
+    assert_eq!(result, Ok(Some(vec![])));
+}
+
+#[modor::test(disabled(wasm))]
+fn map_items_in_parallel_with_panic() {
+    let mut job = modor_jobs::parallel_map(0..1000, |value| {
+        assert_ne!(value, 500, "invalid value");
+        value * 2
+    });
+    let result = retrieve_result(&mut job);
+    assert!(matches!(result, Err(JobError::Panicked(Some(_)))));
+    assert_eq!(job.try_poll(), Ok(None));
+}
+
+fn retrieve_result(job: &mut Job<Vec<usize>>) -> Result<Option<Vec<usize>>, JobError> {
+    const MAX_RETRIES: u32 = 100;
+    for _ in 0..MAX_RETRIES {
+        thread::sleep(Duration::from_millis(10));
+        let result = job.try_poll();
+        if result != Ok(None) {
+            return result;
+        }
+    }
+    panic!("max retries reached");
+}
@@ -0,0 +1,78 @@
+use modor_jobs::{Job, JobError, JobSemaphore};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+#[modor::test(disabled(wasm))]
+fn limit_running_jobs() {
+    let semaphore = JobSemaphore::new(2);
+    let running_count = Arc::new(AtomicUsize::new(0));
+    let max_running_count = Arc::new(AtomicUsize::new(0));
+    let mut jobs: Vec<_> = (0..10)
+        .map(|index| {
+            let running_count = running_count.clone();
+            let max_running_count = max_running_count.clone();
+            Job::new(semaphore.limit(async move {
+                let count = running_count.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running_count.fetch_max(count, Ordering::SeqCst);
+                async_std::task::sleep(Duration::from_millis(20)).await;
+                running_count.fetch_sub(1, Ordering::SeqCst);
+                index
+            }))
+        })
+        .collect();
+    for (index, job) in jobs.iter_mut().enumerate() {
+        assert_eq!(retrieve_result(job), Ok(Some(index)));
+    }
+    assert!(max_running_count.load(Ordering::SeqCst) <= 2);
+}
+
+#[modor::test(disabled(wasm))]
+fn cancel_queued_job() {
+    let semaphore = JobSemaphore::new(1);
+    let mut running_job = Job::new(semaphore.limit(async {
+        async_std::task::sleep(Duration::from_millis(100)).await;
+        1
+    }));
+    thread::sleep(Duration::from_millis(20));
+    let mut cancelled_job = Job::new(semaphore.limit(async { 2 }));
+    let mut queued_job = Job::new(semaphore.limit(async { 3 }));
+    thread::sleep(Duration::from_millis(20));
+    cancelled_job.cancel();
+    assert_eq!(
+        retrieve_result(&mut cancelled_job),
+        Err(JobError::Cancelled)
+    );
+    assert_eq!(retrieve_result(&mut running_job), Ok(Some(1)));
+    assert_eq!(retrieve_result(&mut queued_job), Ok(Some(3)));
+}
+
+#[modor::test(disabled(wasm))]
+fn cancel_running_job() {
+    let semaphore = JobSemaphore::new(1);
+    let mut cancelled_job = Job::new(semaphore.limit(async {
+        async_std::task::sleep(Duration::from_secs(10)).await;
+        1
+    }));
+    thread::sleep(Duration::from_millis(20));
+    let mut queued_job = Job::new(semaphore.limit(async { 2 }));
+    cancelled_job.cancel();
+    assert_eq!(
+        retrieve_result(&mut cancelled_job),
+        Err(JobError::Cancelled)
+    );
+    assert_eq!(retrieve_result(&mut queued_job), Ok(Some(2)));
+}
+
+fn retrieve_result(job: &mut Job<usize>) -> Result<Option<usize>, JobError> {
+    const MAX_RETRIES: u32 = 100;
+    for _ in 0..MAX_RETRIES {
+        thread::sleep(Duration::from_millis(10));
+        let result = job.try_poll();
+        if result != Ok(None) {
+            return result;
+        }
+    }
+    panic!("max retries reached");
+}
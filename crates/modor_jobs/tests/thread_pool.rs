@@ -0,0 +1,32 @@
+#![allow(clippy::unwrap_used)]
+
+use modor_jobs::Job;
+use std::thread;
+use std::time::Duration;
+
+#[modor::test(disabled(wasm))]
+fn run_jobs_with_one_thread() {
+    modor_jobs::set_thread_count(1);
+    let thread_count = modor_jobs::thread_count();
+    let mut jobs: Vec<_> = (0..10).map(|i| Job::new(async move { i * 2 })).collect();
+    let results: Vec<_> = jobs.iter_mut().map(retrieve_result).collect();
+    assert_eq!(thread_count, 1);
+    assert_eq!(results, [0, 2, 4, 6, 8, 10, 12, 14, 16, 18]);
+}
+
+#[modor::test(disabled(wasm))]
+#[should_panic = "job thread count must be greater than zero"]
+fn set_zero_thread_count() {
+    modor_jobs::set_thread_count(0);
+}
+
+fn retrieve_result(job: &mut Job<usize>) -> usize {
+    const MAX_RETRIES: u32 = 100;
+    for _ in 0..MAX_RETRIES {
+        thread::sleep(Duration::from_millis(10));
+        if let Some(result) = job.try_poll().unwrap() {
+            return result;
+        }
+    }
+    panic!("max retries reached");
+}
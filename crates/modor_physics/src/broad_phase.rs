@@ -0,0 +1,295 @@
+use fxhash::{FxHashMap, FxHashSet};
+use modor::{App, State};
+use rapier2d::dynamics::RigidBodySet;
+use rapier2d::geometry::{
+    Aabb, BroadPhaseMultiSap, BroadPhasePairEvent, ColliderHandle, ColliderPair, ColliderSet,
+};
+use rapier2d::math::Real;
+use rapier2d::parry::bounding_volume::BoundingVolume;
+
+/// The configuration of the broad phase, the step that detects potential collisions.
+///
+/// The broad phase only impacts performance: the detected collisions are the same whatever the
+/// configuration.
+///
+/// The configuration is read only once, when the first [`Body2D`](crate::Body2D) is created.
+///
+/// # Examples
+///
+/// ```rust
+/// # use modor::*;
+/// # use modor_physics::*;
+/// #
+/// fn configure_broad_phase(app: &mut App) {
+///     app.get_mut::<BroadPhase>().kind = BroadPhaseKind::SpatialHash { cell_size: 0.1 };
+/// }
+/// ```
+#[non_exhaustive]
+#[derive(Default, Debug, State)]
+pub struct BroadPhase {
+    /// The broad phase algorithm.
+    ///
+    /// Default is [`BroadPhaseKind::SweepAndPrune`].
+    pub kind: BroadPhaseKind,
+}
+
+/// A broad phase algorithm.
+#[non_exhaustive]
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum BroadPhaseKind {
+    /// Sweep-and-prune algorithm.
+    ///
+    /// This is a good default choice for most use cases.
+    #[default]
+    SweepAndPrune,
+    /// Spatial hash algorithm, where the space is divided in square cells of size `cell_size`.
+    ///
+    /// This algorithm is generally more efficient when there are many bodies of similar size,
+    /// in which case `cell_size` should be close to the size of the bodies.
+    ///
+    /// Bodies covering more than 64 cells are checked against all other bodies, so a `cell_size`
+    /// much smaller than the bodies can significantly impact performance.
+    ///
+    /// # Panics
+    ///
+    /// The first [`Body2D`](crate::Body2D) creation panics if `cell_size` is not strictly
+    /// positive.
+    SpatialHash {
+        /// Size of a cell in world units.
+        cell_size: f32,
+    },
+}
+
+pub(crate) fn create(app: &mut App) -> Box<dyn rapier2d::geometry::BroadPhase> {
+    match app.get_mut::<BroadPhase>().kind {
+        BroadPhaseKind::SweepAndPrune => Box::new(BroadPhaseMultiSap::new()),
+        BroadPhaseKind::SpatialHash { cell_size } => {
+            Box::new(SpatialHashBroadPhase::new(cell_size))
+        }
+    }
+}
+
+// colliders covering more cells are checked against all colliders to bound the work per collider
+const MAX_CELLS_PER_COLLIDER: i64 = 64;
+
+struct SpatialHashBroadPhase {
+    cell_size: Real,
+    prediction_distance: Option<Real>,
+    colliders: FxHashMap<ColliderHandle, HashedCollider>,
+    cells: FxHashMap<(i32, i32), Vec<ColliderHandle>>,
+    large_colliders: FxHashSet<ColliderHandle>,
+    pairs: FxHashMap<ColliderHandle, FxHashSet<ColliderHandle>>,
+}
+
+impl SpatialHashBroadPhase {
+    fn new(cell_size: f32) -> Self {
+        assert!(
+            cell_size > 0.,
+            "broad phase cell size must be greater than zero"
+        );
+        Self {
+            cell_size,
+            prediction_distance: None,
+            colliders: FxHashMap::default(),
+            cells: FxHashMap::default(),
+            large_colliders: FxHashSet::default(),
+            pairs: FxHashMap::default(),
+        }
+    }
+
+    fn insert_collider(&mut self, handle: ColliderHandle, aabb: Aabb) {
+        self.remove_from_cells(handle);
+        let cells = CellRange::new(&aabb, self.cell_size);
+        if let Some(cells) = cells {
+            for cell in cells.iter() {
+                self.cells.entry(cell).or_default().push(handle);
+            }
+        } else {
+            self.large_colliders.insert(handle);
+        }
+        self.colliders
+            .insert(handle, HashedCollider { aabb, cells });
+    }
+
+    fn remove_collider(&mut self, handle: ColliderHandle, deleted_pairs: &mut PairSet) {
+        self.remove_from_cells(handle);
+        self.colliders.remove(&handle);
+        for other in self.pairs.remove(&handle).into_iter().flatten() {
+            if let Some(other_pairs) = self.pairs.get_mut(&other) {
+                other_pairs.remove(&handle);
+            }
+            deleted_pairs.insert(Self::pair(handle, other));
+        }
+    }
+
+    fn remove_from_cells(&mut self, handle: ColliderHandle) {
+        match self.colliders.get(&handle).map(|collider| collider.cells) {
+            Some(Some(cells)) => {
+                for cell in cells.iter() {
+                    if let Some(handles) = self.cells.get_mut(&cell) {
+                        handles.retain(|&other| other != handle);
+                        if handles.is_empty() {
+                            self.cells.remove(&cell);
+                        }
+                    }
+                }
+            }
+            Some(None) => {
+                self.large_colliders.remove(&handle);
+            }
+            None => (),
+        }
+    }
+
+    fn update_pairs(
+        &mut self,
+        handle: ColliderHandle,
+        deleted_pairs: &mut PairSet,
+        added_pairs: &mut PairSet,
+    ) {
+        let old_pairs = self.pairs.remove(&handle).unwrap_or_default();
+        let new_pairs = self.intersecting_colliders(handle);
+        for &other in old_pairs.difference(&new_pairs) {
+            if let Some(other_pairs) = self.pairs.get_mut(&other) {
+                other_pairs.remove(&handle);
+            }
+            deleted_pairs.insert(Self::pair(handle, other));
+        }
+        for &other in new_pairs.difference(&old_pairs) {
+            self.pairs.entry(other).or_default().insert(handle);
+            added_pairs.insert(Self::pair(handle, other));
+        }
+        self.pairs.insert(handle, new_pairs);
+    }
+
+    fn intersecting_colliders(&self, handle: ColliderHandle) -> FxHashSet<ColliderHandle> {
+        let collider = &self.colliders[&handle];
+        let candidates: Box<dyn Iterator<Item = ColliderHandle>> = match collider.cells {
+            Some(cells) => Box::new(
+                cells
+                    .iter()
+                    .filter_map(|cell| self.cells.get(&cell))
+                    .flatten()
+                    .chain(&self.large_colliders)
+                    .copied(),
+            ),
+            None => Box::new(self.colliders.keys().copied()),
+        };
+        candidates
+            .filter(|&other| other != handle)
+            .filter(|other| self.colliders[other].aabb.intersects(&collider.aabb))
+            .collect()
+    }
+
+    fn pair(
+        collider1: ColliderHandle,
+        collider2: ColliderHandle,
+    ) -> (ColliderHandle, ColliderHandle) {
+        if collider1.into_raw_parts() <= collider2.into_raw_parts() {
+            (collider1, collider2)
+        } else {
+            (collider2, collider1)
+        }
+    }
+
+    fn sorted_events(
+        pairs: PairSet,
+        event: fn(ColliderPair) -> BroadPhasePairEvent,
+    ) -> Vec<BroadPhasePairEvent> {
+        let mut pairs: Vec<_> = pairs.into_iter().collect();
+        pairs.sort_unstable_by_key(|(collider1, collider2)| {
+            (collider1.into_raw_parts(), collider2.into_raw_parts())
+        });
+        pairs
+            .into_iter()
+            .map(|(collider1, collider2)| event(ColliderPair::new(collider1, collider2)))
+            .collect()
+    }
+}
+
+impl rapier2d::geometry::BroadPhase for SpatialHashBroadPhase {
+    fn update(
+        &mut self,
+        _dt: Real,
+        prediction_distance: Real,
+        colliders: &mut ColliderSet,
+        _bodies: &RigidBodySet,
+        modified_colliders: &[ColliderHandle],
+        removed_colliders: &[ColliderHandle],
+        events: &mut Vec<BroadPhasePairEvent>,
+    ) {
+        let mut deleted_pairs = PairSet::default();
+        let mut added_pairs = PairSet::default();
+        for &handle in removed_colliders {
+            self.remove_collider(handle, &mut deleted_pairs);
+        }
+        let modified_colliders: Vec<_> = if self.prediction_distance == Some(prediction_distance) {
+            modified_colliders.to_vec()
+        } else {
+            self.prediction_distance = Some(prediction_distance);
+            colliders.iter().map(|(handle, _)| handle).collect()
+        };
+        let mut updated_colliders = vec![];
+        for handle in modified_colliders {
+            match colliders.get(handle) {
+                Some(collider) if collider.is_enabled() => {
+                    let aabb = collider.compute_collision_aabb(prediction_distance / 2.);
+                    self.insert_collider(handle, aabb);
+                    updated_colliders.push(handle);
+                }
+                _ => self.remove_collider(handle, &mut deleted_pairs),
+            }
+        }
+        for handle in updated_colliders {
+            self.update_pairs(handle, &mut deleted_pairs, &mut added_pairs);
+        }
+        events.extend(Self::sorted_events(
+            deleted_pairs,
+            BroadPhasePairEvent::DeletePair,
+        ));
+        events.extend(Self::sorted_events(
+            added_pairs,
+            BroadPhasePairEvent::AddPair,
+        ));
+    }
+}
+
+type PairSet = FxHashSet<(ColliderHandle, ColliderHandle)>;
+
+struct HashedCollider {
+    aabb: Aabb,
+    cells: Option<CellRange>,
+}
+
+#[derive(Clone, Copy)]
+struct CellRange {
+    min: (i32, i32),
+    max: (i32, i32),
+}
+
+impl CellRange {
+    fn new(aabb: &Aabb, cell_size: Real) -> Option<Self> {
+        let range = Self {
+            min: (
+                Self::coord(aabb.mins.x, cell_size),
+                Self::coord(aabb.mins.y, cell_size),
+            ),
+            max: (
+                Self::coord(aabb.maxs.x, cell_size),
+                Self::coord(aabb.maxs.y, cell_size),
+            ),
+        };
+        let width = i64::from(range.max.0) - i64::from(range.min.0) + 1;
+        let height = i64::from(range.max.1) - i64::from(range.min.1) + 1;
+        (width * height <= MAX_CELLS_PER_COLLIDER).then_some(range)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn coord(value: Real, cell_size: Real) -> i32 {
+        (value / cell_size).floor() as i32
+    }
+
+    fn iter(self) -> impl Iterator<Item = (i32, i32)> {
+        (self.min.0..=self.max.0).flat_map(move |x| (self.min.1..=self.max.1).map(move |y| (x, y)))
+    }
+}
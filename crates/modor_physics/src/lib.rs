@@ -10,6 +10,7 @@
 //! Now you can start using this crate, for example by creating a [`Body2D`].
 
 mod body;
+mod broad_phase;
 mod collision_group;
 mod collisions;
 mod delta;
@@ -18,6 +19,7 @@ mod pipeline;
 mod user_data;
 
 pub use body::*;
+pub use broad_phase::*;
 pub use collision_group::*;
 pub use collisions::*;
 pub use delta::*;
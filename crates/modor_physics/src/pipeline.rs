@@ -1,3 +1,4 @@
+use crate::broad_phase;
 use crate::collisions::Collision2D;
 use crate::physics_hooks::PhysicsHooks;
 use crate::user_data::ColliderUserData;
@@ -7,7 +8,7 @@ use rapier2d::dynamics::{
     CCDSolver, ImpulseJointSet, IntegrationParameters, IslandManager, MultibodyJointSet,
     RigidBodyHandle, RigidBodySet,
 };
-use rapier2d::geometry::{BroadPhaseMultiSap, Collider, ColliderHandle, ColliderSet, NarrowPhase};
+use rapier2d::geometry::{BroadPhase, Collider, ColliderHandle, ColliderSet, NarrowPhase};
 use rapier2d::na::Vector2;
 use rapier2d::pipeline::PhysicsPipeline;
 use rapier2d::prelude::RigidBody;
@@ -23,7 +24,8 @@ pub(crate) struct Pipeline {
     integration_parameters: IntegrationParameters,
     #[allow(clippy::struct_field_names)]
     physics_pipeline: PhysicsPipeline,
-    broad_phase: BroadPhaseMultiSap,
+    #[from_app(with = broad_phase::create)]
+    broad_phase: Box<dyn BroadPhase>,
     narrow_phase: NarrowPhase,
     ccd_solver: CCDSolver,
     collisions: Vec<Vec<Collision2D>>,
@@ -47,7 +49,7 @@ impl State for Pipeline {
             &Vector2::zeros(),
             &self.integration_parameters,
             &mut self.island_manager,
-            &mut self.broad_phase,
+            &mut *self.broad_phase,
             &mut self.narrow_phase,
             &mut self.rigid_bodies,
             &mut self.colliders,
@@ -0,0 +1,123 @@
+use modor::log::Level;
+use modor::{App, FromApp, Glob, State};
+use modor_math::Vec2;
+use modor_physics::{
+    Body2D, Body2DUpdater, BroadPhase, BroadPhaseKind, CollisionGroup, CollisionGroupUpdater,
+};
+
+#[modor::test(cases(
+    sweep_and_prune = "BroadPhaseKind::SweepAndPrune",
+    small_cells = "BroadPhaseKind::SpatialHash { cell_size: 0.3 }",
+    large_cells = "BroadPhaseKind::SpatialHash { cell_size: 5. }",
+    tiny_cells = "BroadPhaseKind::SpatialHash { cell_size: 0.001 }"
+))]
+fn detect_collisions(kind: BroadPhaseKind) {
+    let positions = [Vec2::ZERO, Vec2::X * 0.9, Vec2::X * 1.8, Vec2::X * 10.];
+    let collisions = collisions(kind, &positions);
+    assert_eq!(collisions, [vec![1], vec![0, 2], vec![1], vec![]]);
+}
+
+#[modor::test(disabled(wasm))]
+fn detect_same_collisions_with_many_bodies() {
+    let positions: Vec<_> = (0..25_u16)
+        .flat_map(|y| (0..40_u16).map(move |x| Vec2::new(f32::from(x) * 0.9, f32::from(y) * 0.95)))
+        .collect();
+    let expected = collisions(BroadPhaseKind::SweepAndPrune, &positions);
+    assert!(expected.iter().all(|collisions| collisions.len() >= 3));
+    for cell_size in [0.05, 0.5, 1., 4.] {
+        let kind = BroadPhaseKind::SpatialHash { cell_size };
+        assert_eq!(collisions(kind, &positions), expected);
+    }
+}
+
+#[modor::test]
+fn remove_collisions_with_spatial_hash() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.get_mut::<BroadPhase>().kind = BroadPhaseKind::SpatialHash { cell_size: 1. };
+    let group = Glob::<CollisionGroup>::from_app(&mut app);
+    CollisionGroupUpdater::new(&group).add_sensor(&mut app, &group);
+    let body1 = Glob::<Body2D>::from_app(&mut app);
+    let body2 = Glob::<Body2D>::from_app(&mut app);
+    for (body, position) in [(&body1, Vec2::ZERO), (&body2, Vec2::X * 0.5)] {
+        Body2DUpdater::default()
+            .position(position)
+            .size(Vec2::ONE)
+            .collision_group(group.to_ref())
+            .apply(&mut app, body);
+    }
+    app.update();
+    assert_eq!(body1.get(&app).collisions().len(), 1);
+    Body2DUpdater::default()
+        .position(Vec2::X * 5.)
+        .apply(&mut app, &body2);
+    app.update();
+    assert_eq!(body1.get(&app).collisions().len(), 0);
+    assert_eq!(body2.get(&app).collisions().len(), 0);
+}
+
+#[modor::test]
+fn delete_body_with_spatial_hash() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.get_mut::<BroadPhase>().kind = BroadPhaseKind::SpatialHash { cell_size: 1. };
+    let group = Glob::<CollisionGroup>::from_app(&mut app);
+    CollisionGroupUpdater::new(&group).add_sensor(&mut app, &group);
+    let body1 = Glob::<Body2D>::from_app(&mut app);
+    let body2 = Glob::<Body2D>::from_app(&mut app);
+    for (body, position) in [(&body1, Vec2::ZERO), (&body2, Vec2::X * 0.5)] {
+        Body2DUpdater::default()
+            .position(position)
+            .size(Vec2::ONE)
+            .collision_group(group.to_ref())
+            .apply(&mut app, body);
+    }
+    app.update();
+    assert_eq!(body1.get(&app).collisions().len(), 1);
+    drop(body2);
+    app.update();
+    assert_eq!(body1.get(&app).collisions().len(), 0);
+}
+
+#[modor::test]
+#[should_panic = "broad phase cell size must be greater than zero"]
+fn configure_spatial_hash_with_zero_cell_size() {
+    let mut app = App::new::<Root>(Level::Info);
+    app.get_mut::<BroadPhase>().kind = BroadPhaseKind::SpatialHash { cell_size: 0. };
+    let _body = Glob::<Body2D>::from_app(&mut app);
+    app.update();
+}
+
+fn collisions(kind: BroadPhaseKind, positions: &[Vec2]) -> Vec<Vec<usize>> {
+    let mut app = App::new::<Root>(Level::Info);
+    app.get_mut::<BroadPhase>().kind = kind;
+    let group = Glob::<CollisionGroup>::from_app(&mut app);
+    CollisionGroupUpdater::new(&group).add_sensor(&mut app, &group);
+    let bodies: Vec<_> = positions
+        .iter()
+        .map(|&position| {
+            let body = Glob::<Body2D>::from_app(&mut app);
+            Body2DUpdater::default()
+                .position(position)
+                .size(Vec2::ONE)
+                .collision_group(group.to_ref())
+                .apply(&mut app, &body);
+            body
+        })
+        .collect();
+    app.update();
+    bodies
+        .iter()
+        .map(|body| {
+            let mut indexes: Vec<_> = body
+                .get(&app)
+                .collisions()
+                .iter()
+                .map(|collision| collision.other_index)
+                .collect();
+            indexes.sort_unstable();
+            indexes
+        })
+        .collect()
+}
+
+#[derive(FromApp, State)]
+struct Root;
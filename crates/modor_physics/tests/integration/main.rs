@@ -1,4 +1,5 @@
 #![allow(clippy::unwrap_used)]
 
 pub mod body;
+pub mod broad_phase;
 pub mod collision_group;
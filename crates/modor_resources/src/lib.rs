@@ -9,9 +9,11 @@
 //!
 //! Now you can start using this crate, for example by defining a [`Resource`].
 
+mod metadata;
 mod resource;
 pub mod testing;
 
+pub use metadata::*;
 pub use resource::*;
 
 pub use modor;
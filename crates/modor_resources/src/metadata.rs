@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+/// The loading metadata of a [`Res`](crate::Res).
+///
+/// Metadata describe the currently loaded version of the resource, and are mainly useful for
+/// debugging purpose.
+///
+/// # Examples
+///
+/// ```rust
+/// # use modor::*;
+/// # use modor_resources::*;
+/// #
+/// fn print_metadata<T: Resource>(app: &App, res: &Glob<Res<T>>) {
+///     let metadata = res.get(app).metadata();
+///     println!(
+///         "Resource loaded from `{}` ({} bytes) in {:?}",
+///         metadata.source().unwrap_or("<none>"),
+///         metadata.size(),
+///         metadata.load_duration(),
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ResourceMetadata {
+    pub(crate) source: Option<String>,
+    pub(crate) size: usize,
+    pub(crate) load_duration: Duration,
+}
+
+impl ResourceMetadata {
+    /// Returns a human-readable label of the source used to load the resource.
+    ///
+    /// The label is the path in case the resource has been loaded from a path, or the [`Debug`]
+    /// representation of the source in case the resource has been loaded from a custom source.
+    ///
+    /// `None` is returned if the resource hasn't been loaded yet.
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// Returns the size in bytes of the loaded file.
+    ///
+    /// The size is zero if the resource hasn't been loaded yet or has been loaded from a custom
+    /// source.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the time taken to load the resource.
+    ///
+    /// The duration is zero if the resource hasn't been loaded yet.
+    pub fn load_duration(&self) -> Duration {
+        self.load_duration
+    }
+}
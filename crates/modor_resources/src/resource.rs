@@ -1,8 +1,10 @@
 use crate::testing::ResourceStates;
+use crate::ResourceMetadata;
 use derivative::Derivative;
+use instant::Instant;
 use modor::log::error;
 use modor::{App, FromApp, Glob, Global, Globals, State};
-use modor_jobs::{AssetLoadingError, AssetLoadingJob, Job};
+use modor_jobs::{AssetLoadingJob, Job, JobError};
 use std::fmt::{Debug, Display, Formatter};
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
@@ -113,6 +115,8 @@ pub struct Res<T: Resource> {
     source: Option<ResSource<T>>,
     loading: Option<Loading<T>>,
     state: ResourceState,
+    metadata: ResourceMetadata,
+    loading_start: Instant,
     index: usize,
 }
 
@@ -158,13 +162,20 @@ where
         &self.state
     }
 
+    /// Returns the loading metadata of the resource.
+    pub fn metadata(&self) -> &ResourceMetadata {
+        &self.metadata
+    }
+
     fn reload(&mut self, app: &mut App) {
         self.state = ResourceState::Loading;
         self.loading = None;
+        self.loading_start = Instant::now();
         match &self.source {
             Some(ResSource::Path(path)) => {
                 self.loading = Some(Loading::Path(AssetLoadingJob::new(path, |t| async {
-                    T::load_from_file(t)
+                    let size = t.len();
+                    T::load_from_file(t).map(|loaded| (loaded, size))
                 })));
             }
             Some(ResSource::Source(source)) => {
@@ -188,28 +199,36 @@ where
     fn update(&mut self, app: &mut App) {
         match self.loading.take() {
             Some(Loading::Path(mut job)) => match job.try_poll() {
-                Ok(Some(Ok(loaded))) => self.success(app, loaded),
+                Ok(Some(Ok((loaded, size)))) => self.success(app, loaded, size),
                 Ok(Some(Err(err))) => self.fail(err),
                 Ok(None) => self.loading = Some(Loading::Path(job)),
                 Err(err) => self.fail(ResourceError::Loading(err)),
             },
             Some(Loading::Source(mut job)) => match job.try_poll() {
-                Ok(Some(Ok(loaded))) => self.success(app, loaded),
+                Ok(Some(Ok(loaded))) => self.success(app, loaded, 0),
                 Ok(Some(Err(err))) => self.fail(err),
                 Ok(None) => self.loading = Some(Loading::Source(job)),
                 Err(err) => self.fail(ResourceError::Other(err.to_string())),
             },
-            Some(Loading::Sync(loaded)) => self.success(app, loaded),
+            Some(Loading::Sync(loaded)) => self.success(app, loaded, 0),
             None => (),
         }
     }
 
-    fn success(&mut self, app: &mut App, loaded: T::Loaded) {
+    fn success(&mut self, app: &mut App, loaded: T::Loaded, size: usize) {
         let source = self
             .source
             .as_ref()
             .expect("internal error: missing source");
         self.state = ResourceState::Loaded;
+        self.metadata = ResourceMetadata {
+            source: Some(match source {
+                ResSource::Path(path) => path.clone(),
+                ResSource::Source(source) => format!("{source:?}"),
+            }),
+            size,
+            load_duration: Instant::now().duration_since(self.loading_start),
+        };
         self.inner.on_load(app, self.index, loaded, source);
     }
 
@@ -305,7 +324,7 @@ pub trait Source: Clone + Send + Debug + 'static {
 #[non_exhaustive]
 pub enum ResourceError {
     /// There was an error while loading the asset.
-    Loading(AssetLoadingError),
+    Loading(JobError),
     /// There was an error while loading or parsing the resource.
     Other(String),
 }
@@ -410,7 +429,9 @@ where
 #[derive(Derivative)]
 #[derivative(Debug)]
 enum Loading<T: Resource> {
-    Path(#[derivative(Debug = "ignore")] AssetLoadingJob<Result<T::Loaded, ResourceError>>),
+    Path(
+        #[derivative(Debug = "ignore")] AssetLoadingJob<Result<(T::Loaded, usize), ResourceError>>,
+    ),
     Source(#[derivative(Debug = "ignore")] Job<Result<T::Loaded, ResourceError>>),
     Sync(#[derivative(Debug = "ignore")] T::Loaded),
 }
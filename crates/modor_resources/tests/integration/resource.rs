@@ -1,6 +1,6 @@
 use modor::log::Level;
 use modor::{App, FromApp, Glob, State, Updater};
-use modor_jobs::AssetLoadingError;
+use modor_jobs::JobError;
 use modor_resources::{
     testing, Res, ResSource, ResUpdater, Resource, ResourceError, ResourceState, Source,
 };
@@ -64,13 +64,13 @@ fn load_resource_from_invalid_path() {
     assert_eq!(res.get(&app).size, None);
     assert!(matches!(
         res.get(&app).state().error(),
-        Some(ResourceError::Loading(AssetLoadingError::IoError(_)))
+        Some(ResourceError::Loading(JobError::NotFound(_)))
     ));
     app.update();
     assert_eq!(res.get(&app).size, None);
     assert!(matches!(
         res.get(&app).state().error(),
-        Some(ResourceError::Loading(AssetLoadingError::IoError(_)))
+        Some(ResourceError::Loading(JobError::NotFound(_)))
     ));
 }
 
@@ -124,7 +124,9 @@ fn load_resource_from_panicking_source() {
     ContentSizeUpdater::default()
         .res(ResUpdater::default().source(ContentSizeSource::Panicking))
         .apply(&mut app, &res);
-    let error = ResourceState::Error(ResourceError::Other("job has panicked".into()));
+    let error = ResourceState::Error(ResourceError::Other(
+        "job has panicked: explicit panic".into(),
+    ));
     testing::wait_resources(&mut app);
     assert_eq!(res.get(&app).size, None);
     assert_eq!(res.get(&app).state(), &error);
@@ -133,6 +135,46 @@ fn load_resource_from_panicking_source() {
     assert_eq!(res.get(&app).state(), &error);
 }
 
+#[modor::test(disabled(wasm))]
+fn retrieve_metadata_before_loading() {
+    let mut app = App::new::<Root>(Level::Info);
+    let res = Glob::<Res<ContentSize>>::from_app(&mut app);
+    ContentSizeUpdater::default()
+        .res(ResUpdater::default().path("not_empty.txt"))
+        .apply(&mut app, &res);
+    let metadata = res.get(&app).metadata();
+    assert_eq!(metadata.source(), None);
+    assert_eq!(metadata.size(), 0);
+    assert_eq!(metadata.load_duration(), Duration::ZERO);
+}
+
+#[modor::test(disabled(wasm))]
+fn retrieve_metadata_of_resource_loaded_from_path() {
+    let mut app = App::new::<Root>(Level::Info);
+    let res = Glob::<Res<ContentSize>>::from_app(&mut app);
+    ContentSizeUpdater::default()
+        .res(ResUpdater::default().path("not_empty.txt"))
+        .apply(&mut app, &res);
+    testing::wait_resources(&mut app);
+    let metadata = res.get(&app).metadata();
+    assert_eq!(metadata.source(), Some("not_empty.txt"));
+    assert_eq!(metadata.size(), 12);
+    assert!(metadata.load_duration() >= Duration::from_millis(10));
+}
+
+#[modor::test(disabled(wasm))]
+fn retrieve_metadata_of_resource_loaded_from_source() {
+    let mut app = App::new::<Root>(Level::Info);
+    let res = Glob::<Res<ContentSize>>::from_app(&mut app);
+    ContentSizeUpdater::default()
+        .res(ResUpdater::default().source(ContentSizeSource::SyncStr("content")))
+        .apply(&mut app, &res);
+    let metadata = res.get(&app).metadata();
+    assert_eq!(metadata.source(), Some("SyncStr(\"content\")"));
+    assert_eq!(metadata.size(), 0);
+    assert!(metadata.load_duration() >= Duration::from_millis(10));
+}
+
 #[modor::test(disabled(wasm))]
 fn set_source() {
     let mut app = App::new::<Root>(Level::Info);